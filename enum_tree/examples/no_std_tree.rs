@@ -0,0 +1,76 @@
+//! A `#![no_std]` library using the derive. Generated code must only name `core`: this crate
+//! doesn't link `std`, so anything reaching for `std` paths or the `std` prelude fails to build.
+//!
+//! Checked with `cargo build -p enum_tree --no-default-features --example no_std_tree`.
+#![no_std]
+
+use enum_tree::{
+    EnumTree, EnumTreeNodePath, ToEnumTreeRootAt, TryFromEnumTreeRoot, TryFromEnumTreeRootAt,
+    TryFromEnumTreeRootVia, TryFromEnumTreeRootWithContext,
+};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum Firmware {
+    Motor(Motor),
+    Panel(Panel),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(Firmware, Firmware)]
+pub enum Motor {
+    Axis {
+        index: u8,
+        #[enum_tree(child)]
+        speed: Speed,
+    },
+    Confirm(Confirm),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(Firmware, Firmware)]
+pub enum Panel {
+    Left(Button),
+    Right(Button),
+    Confirm(Confirm),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Motor, Firmware, variant = Axis)]
+pub enum Speed {
+    Set { rpm: u16 },
+    Stop,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Panel, Firmware, variant = Left)]
+pub enum Button {
+    Press,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Motor, Firmware)]
+#[enum_tree_leaf(Panel, Firmware)]
+pub enum Confirm {
+    Yes,
+}
+
+/// Runs every kind of conversion, so that their generated impls are instantiated too.
+pub fn conversions() -> bool {
+    let root = Firmware::Motor(Motor::Axis {
+        index: 2,
+        speed: Speed::Set { rpm: 900 },
+    });
+    let with_context =
+        Speed::from_root_with_context(root) == Some((Speed::Set { rpm: 900 }, (((), ()), (2,))));
+    let mismatch = Speed::from_root(Firmware::Panel(Panel::Confirm(Confirm::Yes))).is_none();
+
+    let root = Button::Press.to_root_at(Panel::Right);
+    let at = Button::from_root_at(root) == Some((Button::Press, "Right"));
+
+    let root = Firmware::Panel(Panel::Confirm(Confirm::Yes));
+    let via = Confirm::from_root_via(root)
+        == Some((Confirm::Yes, <Panel as EnumTreeNodePath<Firmware>>::PATH));
+
+    with_context && mismatch && at && via
+}
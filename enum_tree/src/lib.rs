@@ -1,3 +1,10 @@
+//! Traits for trees of enums. The crate is `no_std`; enable the `std` feature for extras that
+//! need the standard library.
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
 /// Trait for nodes in an enum tree.
 ///
 /// `R` is the root enum type for the tree this node belongs to and `P` is the
@@ -44,17 +51,884 @@ where
 //     }
 // }
 
+/// Extract a node from the root `R`.
+///
+/// A node with several parents in the tree is looked for under each of them in declaration
+/// order, so it is found whichever parent holds it.
 pub trait TryFromEnumTreeRoot<R>: EnumTree<R> {
     fn from_root(root: R) -> Option<Self>;
+
+    /// Like `from_root`, but a root holding another node is handed back in the error, which
+    /// names the variant expected and the one found instead, on the path through the first
+    /// parent.
+    fn try_from_root(root: R) -> Result<Self, Mismatch<R>>;
 }
 
 impl<T, Root> TryFromEnumTreeRoot<Root> for T
 where
-    T: EnumTree<Root> + TryFrom<<T as EnumTree<Root>>::P, Error = ()>,
-    <T as EnumTree<Root>>::P: EnumTree<Root> + TryFromEnumTreeRoot<Root>,
+    T: EnumTree<Root> + __private::Ancestry<Root>,
 {
     fn from_root(root: Root) -> Option<Self> {
-        let p: <T as EnumTree<Root>>::P = <T as EnumTree<Root>>::P::from_root(root)?;
-        <T as TryFrom<<T as EnumTree<Root>>::P>>::try_from(p).ok()
+        T::try_from_root(root).ok()
+    }
+
+    fn try_from_root(root: Root) -> Result<Self, Mismatch<Root>> {
+        T::from_parents(root).map(|(node, _)| node)
+    }
+}
+
+/// A failed conversion down the tree, handing back the value that didn't convert.
+///
+/// Both names are variants of the enum where the paths part: `expected` is the variant leading to
+/// the node asked for (the first one, if several hold it) and `found` the variant held instead.
+/// Extracting `SettingsAction` from `AppAction::Menu(MenuAction::Network(..))` expects `Settings`
+/// and finds `Network`, and extracting it from `AppAction::Quit` expects `Menu` and finds `Quit`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mismatch<T> {
+    value: T,
+    expected: &'static str,
+    found: &'static str,
+}
+
+impl<T> Mismatch<T> {
+    pub fn new(value: T, expected: &'static str, found: &'static str) -> Self {
+        Mismatch {
+            value,
+            expected,
+            found,
+        }
+    }
+
+    /// The value that didn't convert, unchanged.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The variant leading to the node that was asked for.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// The variant held instead of the expected one.
+    pub fn found(&self) -> &'static str {
+        self.found
+    }
+}
+
+impl<T> core::fmt::Display for Mismatch<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected `{}`, found `{}`", self.expected, self.found)
+    }
+}
+
+impl<T: core::fmt::Debug> core::error::Error for Mismatch<T> {}
+
+/// Extract a node from its immediate parent `P`, together with the other fields of the variant
+/// wrapping it.
+///
+/// Implemented by the parent's derive for every child it wraps. A variant like
+/// `Buffer { id: BufferId, #[enum_tree(child)] action: BufferAction }` gives
+/// `BufferAction` the context `(BufferId,)`; a plain `V(Child)` variant has the context `()`.
+pub trait TryFromParentWithContext<P>: Sized {
+    /// The wrapping variant's other fields, as a tuple in declaration order.
+    type Context;
+    fn try_from_parent_with_context(parent: P) -> Option<(Self, Self::Context)>;
+}
+
+/// Like [`TryFromEnumTreeRoot`], but also returns the context fields collected on the way down
+/// from the root.
+///
+/// The context nests one level per edge, root first: with `Root::Editor(Editor)` and the
+/// `Editor::Buffer { id: BufferId, #[enum_tree(child)] action: BufferAction }` variant, the
+/// context of `BufferAction` is `(((), ()), (BufferId,))`.
+///
+/// A node with several parents in the tree is looked for under each of them, like in
+/// `from_root`, as long as they all give it the same context type; otherwise this trait isn't
+/// implemented for it.
+pub trait TryFromEnumTreeRootWithContext<R>: EnumTree<R> {
+    type Context;
+    fn from_root_with_context(root: R) -> Option<(Self, Self::Context)>;
+}
+
+impl<T, Root> TryFromEnumTreeRootWithContext<Root> for T
+where
+    T: EnumTree<Root> + __private::FromParentsWithContext<Root>,
+{
+    type Context = <T as __private::FromParentsWithContext<Root>>::Context;
+
+    fn from_root_with_context(root: Root) -> Option<(Self, Self::Context)> {
+        T::from_parents_with_context(root)
+    }
+}
+
+/// Borrow a node out of its immediate parent `P`: the borrowing counterpart of `TryFrom<P>`.
+///
+/// Implemented by the parent's derive for every child it wraps. A child held in an `Rc` or
+/// `Arc` is cloned out of a shared pointer by `try_from_parent_mut` (`make_mut`), so it must be
+/// `Clone`.
+pub trait TryFromParentRef<P>: Sized {
+    fn try_from_parent_ref(parent: &P) -> Result<&Self, Mismatch<&P>>;
+    fn try_from_parent_mut(parent: &mut P) -> Result<&mut Self, Mismatch<&mut P>>;
+}
+
+/// Borrow a node inside the root `R`, without taking or cloning the root.
+///
+/// Follows the same parent chain as [`TryFromEnumTreeRoot`].
+pub trait TryFromEnumTreeRootRef<R>: EnumTree<R> {
+    fn from_root_ref(root: &R) -> Option<&Self>;
+    fn from_root_mut(root: &mut R) -> Option<&mut Self>;
+
+    /// Like `from_root_ref`, naming the node expected and the variant found on a mismatch.
+    fn try_from_root_ref(root: &R) -> Result<&Self, Mismatch<&R>>;
+}
+
+impl<T, Root> TryFromEnumTreeRootRef<Root> for T
+where
+    T: EnumTree<Root> + __private::Ancestry<Root>,
+{
+    fn from_root_ref(root: &Root) -> Option<&Self> {
+        Self::try_from_root_ref(root).ok()
+    }
+
+    fn from_root_mut(root: &mut Root) -> Option<&mut Self> {
+        T::from_parents_mut(root)
+    }
+
+    fn try_from_root_ref(root: &Root) -> Result<&Self, Mismatch<&Root>> {
+        T::from_parents_ref(root)
+    }
+}
+
+/// Convert a node to the root `R`, wrapping it in an explicit variant of its parent.
+///
+/// A child held in several variants of its parent, e.g. `Primary(Button)` and
+/// `Secondary(Button)`, has no `From` impl into that parent, so `to_root` doesn't apply to it.
+/// `position` picks the variant instead: `Button::Ok.to_root_at(Toolbar::Secondary)`.
+pub trait ToEnumTreeRootAt<R>: EnumTree<R> {
+    fn to_root_at(self, position: impl FnOnce(Self) -> Self::P) -> R;
+}
+
+impl<T, Root> ToEnumTreeRootAt<Root> for T
+where
+    T: EnumTree<Root>,
+    <T as EnumTree<Root>>::P: EnumTree<Root> + ToEnumTreeRoot<Root>,
+{
+    fn to_root_at(self, position: impl FnOnce(Self) -> <T as EnumTree<Root>>::P) -> Root {
+        position(self).to_root()
+    }
+}
+
+/// Extract a node from the root `R`, together with the name of the parent variant it was held
+/// in. Tells apart the positions of a child held in several variants of its parent.
+///
+/// Like `from_root`, a node with several parents in the tree is looked for under each of them;
+/// the variant is the one of the parent it was found under.
+pub trait TryFromEnumTreeRootAt<R>: EnumTree<R> {
+    fn from_root_at(root: R) -> Option<(Self, &'static str)>;
+}
+
+impl<T, Root> TryFromEnumTreeRootAt<Root> for T
+where
+    T: EnumTree<Root> + __private::Ancestry<Root>,
+    // The root isn't held in any variant.
+    <T as EnumTree<Root>>::P: EnumTree<Root>,
+{
+    fn from_root_at(root: Root) -> Option<(Self, &'static str)> {
+        T::from_parents_at(root)
+    }
+}
+
+/// Convert a node to the root `R` through a chosen parent `P`.
+///
+/// A node may declare several parents in the same tree, e.g. a `Confirm` dialog under both
+/// `File` and `Network`; `to_root` goes through the first one, `to_root_via::<Network>()`
+/// through any of them.
+pub trait ToEnumTreeRootVia<R>: EnumTree<R> {
+    fn to_root_via<P>(self) -> R
+    where
+        P: From<Self> + ToEnumTreeRoot<R>;
+}
+
+impl<T, Root> ToEnumTreeRootVia<Root> for T
+where
+    T: EnumTree<Root>,
+{
+    fn to_root_via<P>(self) -> Root
+    where
+        P: From<T> + ToEnumTreeRoot<Root>,
+    {
+        P::from(self).to_root()
+    }
+}
+
+/// Extract a node from the root `R` like `from_root`, together with the path of the parent it was
+/// found under. Tells apart the parents of a node with several parents in the tree: the path is
+/// the parent's [`EnumTreeNodePath::PATH`], the same whichever way the parent is spelled, so
+/// `from_root_via` finding the node under `P` can be checked with
+/// `path == <P as EnumTreeNodePath<R>>::PATH`, matching `to_root_via::<P>()`.
+pub trait TryFromEnumTreeRootVia<R>: EnumTree<R> {
+    fn from_root_via(root: R) -> Option<(Self, &'static NodePath)>;
+}
+
+impl<T, Root> TryFromEnumTreeRootVia<Root> for T
+where
+    T: EnumTree<Root> + __private::Ancestry<Root>,
+    // The root has no parent to name.
+    <T as EnumTree<Root>>::P: EnumTree<Root>,
+{
+    fn from_root_via(root: Root) -> Option<(Self, &'static NodePath)> {
+        T::from_parents(root).ok()
+    }
+}
+
+/// Convert a node into any of its ancestors `A` in the tree rooted at `R`, not only the root.
+///
+/// Goes up through the parents' `From` impls along `EnumTree::P`: `Settings::ToggleSound
+/// .to_ancestor::<Menu>()` stops at `Menu`. Every node counts as its own ancestor.
+pub trait ToEnumTreeAncestor<R>: EnumTree<R> {
+    fn to_ancestor<A>(self) -> A
+    where
+        Self: __private::SelfOrDescendant<A, R>;
+}
+
+impl<T, Root> ToEnumTreeAncestor<Root> for T
+where
+    T: EnumTree<Root>,
+{
+    fn to_ancestor<A>(self) -> A
+    where
+        T: __private::SelfOrDescendant<A, Root>,
+    {
+        __private::SelfOrDescendant::<A, Root>::into_ancestor(self)
+    }
+}
+
+/// Extract a node from any of its ancestors `A` in the tree rooted at `R`: the counterpart of
+/// [`ToEnumTreeAncestor`], e.g. `Settings::try_from_ancestor(menu)`.
+///
+/// Like [`TryFromEnumTreeRoot::try_from_root`], a mismatch hands the ancestor back.
+pub trait TryFromEnumTreeAncestor<R>: EnumTree<R> {
+    fn try_from_ancestor<A>(ancestor: A) -> Result<Self, Mismatch<A>>
+    where
+        Self: __private::FromSelfOrAncestor<A, R>;
+}
+
+impl<T, Root> TryFromEnumTreeAncestor<Root> for T
+where
+    T: EnumTree<Root>,
+{
+    fn try_from_ancestor<A>(ancestor: A) -> Result<Self, Mismatch<A>>
+    where
+        T: __private::FromSelfOrAncestor<A, Root>,
+    {
+        __private::FromSelfOrAncestor::<A, Root>::try_from_ancestor(ancestor)
+    }
+}
+
+/// `Self` lies below `A` in the tree rooted at `R`, at any depth.
+///
+/// Implemented for every node reaching `A` through its first parent in `R`, i.e. every pair
+/// [`ToEnumTreeAncestor`] and [`TryFromEnumTreeAncestor`] connect, so generic code can bound
+/// on `T: DescendantOf<Menu, AppAction>` and convert with them. A node is not its own
+/// descendant, and the root is not a descendant of anything.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a descendant of `{A}` in the tree rooted at `{R}`",
+    label = "`{Self}` is not below `{A}`"
+)]
+pub trait DescendantOf<A, R>:
+    EnumTree<R> + __private::SelfOrDescendant<A, R> + __private::FromSelfOrAncestor<A, R>
+{
+}
+
+// A failed bound is reported as `DescendantOf` itself rather than traced into these bounds.
+#[diagnostic::do_not_recommend]
+impl<T, A, R> DescendantOf<A, R> for T
+where
+    T: EnumTree<R> + __private::SelfOrDescendant<A, R> + __private::FromSelfOrAncestor<A, R>,
+    // Only a node whose parent is `A` or below it is strictly below `A`; the root's parent `()`
+    // is not a node.
+    <T as EnumTree<R>>::P: EnumTree<R> + __private::SelfOrDescendant<A, R>,
+{
+}
+
+/// `Self` lies above `D` in the tree rooted at `R`: the converse of [`DescendantOf`].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an ancestor of `{D}` in the tree rooted at `{R}`",
+    label = "`{Self}` is not above `{D}`"
+)]
+pub trait AncestorOf<D, R>: Sized {
+    /// Lifts `descendant` up to `Self`, like `descendant.to_ancestor::<Self>()`.
+    fn from_descendant(descendant: D) -> Self;
+
+    /// Extracts the descendant, handing `self` back if it holds something else.
+    fn try_into_descendant(self) -> Result<D, Mismatch<Self>>;
+}
+
+#[diagnostic::do_not_recommend]
+impl<A, D, R> AncestorOf<D, R> for A
+where
+    D: DescendantOf<A, R>,
+{
+    fn from_descendant(descendant: D) -> A {
+        __private::SelfOrDescendant::<A, R>::into_ancestor(descendant)
+    }
+
+    fn try_into_descendant(self) -> Result<D, Mismatch<A>> {
+        __private::FromSelfOrAncestor::<A, R>::try_from_ancestor(self)
+    }
+}
+
+/// The variant names a value holds, from the value down to the leaf variant, for logs and
+/// analytics.
+///
+/// Implemented by the derive for every node. `AppAction::Menu(Menu::Settings(Settings::Reset))`
+/// has the path `Menu/Settings/Reset`; a node's full path from the root is its
+/// [`EnumTreeNodePath::PATH`] followed by its `tree_path`.
+pub trait EnumTreePath {
+    /// The variant `self` holds, and the node it wraps if it wraps one. A struct leaf holds no
+    /// variant.
+    fn path_step(&self) -> Option<(&'static str, Option<&dyn EnumTreePath>)>;
+
+    fn tree_path(&self) -> TreePath<'_>
+    where
+        Self: Sized,
+    {
+        TreePath { next: Some(self) }
+    }
+}
+
+/// Iterator over the variant names from a value down to its leaf variant, returned by
+/// [`EnumTreePath::tree_path`]. Displays as the names joined with `/`.
+#[derive(Clone)]
+pub struct TreePath<'a> {
+    next: Option<&'a dyn EnumTreePath>,
+}
+
+impl Iterator for TreePath<'_> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<&'static str> {
+        let (variant, child) = self.next.take()?.path_step()?;
+        self.next = child;
+        Some(variant)
+    }
+}
+
+impl core::fmt::Display for TreePath<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, variant) in self.clone().enumerate() {
+            if i > 0 {
+                f.write_str("/")?;
+            }
+            f.write_str(variant)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for TreePath<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// The path of a node type from the root `R`: the variant names wrapping it on the way down,
+/// through its first parent at each level.
+///
+/// `<Settings as EnumTreeNodePath<AppAction>>::PATH` is `Menu/Settings` when
+/// `AppAction::Menu(Menu)` wraps `Menu::Settings(Settings)`; the root's path is empty. A child
+/// its parent holds in several variants goes through the one it names with `variant = V`.
+pub trait EnumTreeNodePath<R>: EnumTree<R> {
+    const PATH: &'static NodePath;
+}
+
+/// The paths of every node of a tree, root first, in declaration order.
+///
+/// Implemented by `enum_tree!` for the root of the tree it declares, since only the macro sees
+/// the whole tree.
+pub trait EnumTreeNodes: EnumTreeRoot<Self> {
+    const NODES: &'static [&'static NodePath];
+}
+
+/// A node's [`EnumTreeNodePath::PATH`], built at compile time from its parent's path. Displays
+/// as the variant names joined with `/`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NodePath {
+    /// The parent's path and the variant wrapping the node in it; `None` for the root.
+    up: Option<(&'static NodePath, &'static str)>,
+}
+
+impl NodePath {
+    /// The root's path, which is empty.
+    pub const ROOT: NodePath = NodePath { up: None };
+
+    /// The path of a node held in the `variant` of a parent at `parent`.
+    pub const fn child(parent: &'static NodePath, variant: &'static str) -> NodePath {
+        NodePath {
+            up: Some((parent, variant)),
+        }
+    }
+
+    /// The number of variants from the root down to the node.
+    pub const fn len(&self) -> usize {
+        match self.up {
+            Some((parent, _)) => parent.len() + 1,
+            None => 0,
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.up.is_none()
+    }
+
+    /// The variant wrapping the node in its parent; `None` for the root.
+    pub const fn variant(&self) -> Option<&'static str> {
+        match self.up {
+            Some((_, variant)) => Some(variant),
+            None => None,
+        }
+    }
+
+    /// The variant names, root first.
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        // Paths are short and linked towards the root, so each name is looked up from the end.
+        (0..self.len()).rev().map(move |depth| {
+            let mut path = self;
+            for _ in 0..depth {
+                path = path.up.unwrap().0;
+            }
+            path.variant().unwrap()
+        })
+    }
+}
+
+impl core::fmt::Display for NodePath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some((parent, variant)) = self.up {
+            if !parent.is_empty() {
+                write!(f, "{parent}/")?;
+            }
+            f.write_str(variant)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for NodePath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Support items for code generated by the derive. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    /// Implemented by a parent enum for every child type it wraps, naming the wrapping variants.
+    #[diagnostic::on_unimplemented(
+        message = "parent `{Self}` has no variant wrapping `{C}`",
+        label = "`{Self}` is declared as the parent of `{C}` here",
+        note = "add a variant `{C}({C})` to `{Self}`, or name the variant with `variant = ...`"
+    )]
+    pub trait ParentOf<C> {
+        /// The variants holding a `C`, in declaration order.
+        const VARIANTS: &'static [&'static str];
+
+        /// The variant `self` holds its `C` in; only meaningful if it holds one.
+        fn position(&self) -> &'static str {
+            Self::VARIANTS[0]
+        }
+    }
+
+    /// Implemented by a child for every (parent, root) pair it declares, including the parents
+    /// after the first one in a tree, which aren't its `EnumTree::P`. A child that leaves its root
+    /// out declares the parent in every tree the parent is in.
+    #[diagnostic::on_unimplemented(
+        message = "`{P}` wraps `{Self}`, which does not declare `{P}` as its parent in the tree rooted at `{R}`",
+        label = "`{Self}` does not declare `{P}` as its parent",
+        note = "add `#[enum_tree_inner({P}, {R})]` or `#[enum_tree_leaf({P}, {R})]` to `{Self}`"
+    )]
+    pub trait ChildOf<P, R> {}
+
+    /// Implemented by the root and by every inner node, naming the root that
+    /// `#[enum_tree(parent = P, kind = ..)]` infers when the root is left out: the root of the
+    /// single tree `P` belongs to. A node in several trees names a type of its own instead, so that
+    /// its children fail [`assert_single_root`] once and their impls for that type never apply.
+    pub trait SingleRoot {
+        type Root;
+    }
+
+    /// Implemented by every root, for the parents `P` it is inferred from.
+    #[diagnostic::on_unimplemented(
+        message = "cannot infer the root from parent `{P}`",
+        label = "`{P}` is not the root or an inner node of exactly one tree",
+        note = "name the root with `root = ...`"
+    )]
+    pub trait SingleRootOf<P> {}
+
+    #[diagnostic::do_not_recommend]
+    impl<P, R: crate::EnumTreeRoot<R>> SingleRootOf<P> for R {}
+
+    /// Implemented by a parent in the same tree as `P`, for children that infer their root from
+    /// both.
+    #[diagnostic::on_unimplemented(
+        message = "cannot infer one root for parents `{P}` and `{Self}`",
+        label = "`{Self}` is not in the tree of `{P}`",
+        note = "name the root with `root = ...`"
+    )]
+    pub trait SameRoot<P> {}
+
+    #[diagnostic::do_not_recommend]
+    impl<T, P> SameRoot<P> for T
+    where
+        T: SingleRoot,
+        P: SingleRoot<Root = T::Root>,
+    {
+    }
+
+    /// Implemented by a root for every tree it is mounted in as an inner node. Nodes that only
+    /// declare the mounted root join those trees through it, with the same parents.
+    pub trait MountedIn<R> {}
+
+    /// `Self` is `A` or a descendant of `A` in the tree rooted at `R`, and converts into it
+    /// through its parents' `From` impls.
+    ///
+    /// The bound of `to_ancestor`. `A` is found by its depth in the tree, as the ancestor that
+    /// many steps up along `EnumTree::P`; a failed bound is not traced into the steps, so that it
+    /// is reported at the node that was converted.
+    #[diagnostic::on_unimplemented(
+        message = "`{A}` is not `{Self}` or one of its ancestors in the tree rooted at `{R}`",
+        label = "`{Self}` can't be converted into `{A}`",
+        note = "the parents of `{Self}` were followed up to `{R}` without reaching `{A}`"
+    )]
+    pub trait SelfOrDescendant<A, R>: Sized {
+        fn into_ancestor(self) -> A;
+    }
+
+    #[diagnostic::do_not_recommend]
+    impl<T, A, R> SelfOrDescendant<A, R> for T
+    where
+        T: Ancestry<R> + Up<Distance<T, A, R>, R, Ancestor = A>,
+        A: Ancestry<R>,
+        T::Depth: Minus<A::Depth>,
+    {
+        fn into_ancestor(self) -> A {
+            Up::<Distance<T, A, R>, R>::up(self)
+        }
+    }
+
+    /// `Self` is `A` or a descendant of `A` in the tree rooted at `R`, and is extracted from it
+    /// through its parents' `TryFrom` and `TryFromParentRef` impls.
+    ///
+    /// The downward counterpart of `SelfOrDescendant`, stepped by `Down`.
+    #[diagnostic::on_unimplemented(
+        message = "`{A}` is not `{Self}` or one of its ancestors in the tree rooted at `{R}`",
+        label = "`{Self}` can't be extracted from `{A}`",
+        note = "the parents of `{Self}` were followed up to `{R}` without reaching `{A}`"
+    )]
+    pub trait FromSelfOrAncestor<A, R>: Sized {
+        fn try_from_ancestor(ancestor: A) -> Result<Self, crate::Mismatch<A>>;
+    }
+
+    #[diagnostic::do_not_recommend]
+    impl<T, A, R> FromSelfOrAncestor<A, R> for T
+    where
+        T: Ancestry<R> + Down<Distance<T, A, R>, R, Ancestor = A>,
+        A: Ancestry<R>,
+        T::Depth: Minus<A::Depth>,
+    {
+        fn try_from_ancestor(ancestor: A) -> Result<T, crate::Mismatch<A>> {
+            Down::<Distance<T, A, R>, R>::try_from_ancestor(ancestor)
+        }
+    }
+
+    /// The depth of the root in `Ancestry::Depth`.
+    pub struct Zero;
+
+    /// The depth of a child of a node at depth `N`.
+    pub struct Succ<N>(core::marker::PhantomData<N>);
+
+    /// `Self - N` for depths; only defined when `N` is not deeper than `Self`.
+    pub trait Minus<N> {
+        type Output;
+    }
+
+    impl<M> Minus<Zero> for M {
+        type Output = M;
+    }
+
+    impl<M: Minus<N>, N> Minus<Succ<N>> for Succ<M> {
+        type Output = M::Output;
+    }
+
+    /// The number of steps from `T` up to `A` in the tree rooted at `R`.
+    pub type Distance<T, A, R> =
+        <<T as Ancestry<R>>::Depth as Minus<<A as Ancestry<R>>::Depth>>::Output;
+
+    /// Goes up `N` steps from `Self` along `EnumTree::P`, through the parents' `From` impls.
+    pub trait Up<N, R>: Sized {
+        type Ancestor;
+        fn up(self) -> Self::Ancestor;
+    }
+
+    impl<T, R> Up<Zero, R> for T {
+        type Ancestor = T;
+
+        fn up(self) -> T {
+            self
+        }
+    }
+
+    impl<T, N, R> Up<Succ<N>, R> for T
+    where
+        T: crate::EnumTree<R>,
+        T::P: From<T> + Up<N, R>,
+    {
+        type Ancestor = <T::P as Up<N, R>>::Ancestor;
+
+        fn up(self) -> Self::Ancestor {
+            T::P::from(self).up()
+        }
+    }
+
+    /// Extracts `Self` from its ancestor `N` steps up along `EnumTree::P`, through the parents'
+    /// `TryFrom` impls. The way down is checked on a borrow first, so that a mismatch hands the
+    /// ancestor back.
+    pub trait Down<N, R>: Sized {
+        type Ancestor;
+        /// The nodes between `Ancestor` and `Self`, which a borrow of the ancestor passes through;
+        /// `try_from_ancestor_ref` takes a marker borrowing them so that they outlive it.
+        type Between;
+        fn try_from_ancestor(
+            ancestor: Self::Ancestor,
+        ) -> Result<Self, crate::Mismatch<Self::Ancestor>>;
+        fn try_from_ancestor_ref<'a>(
+            ancestor: &'a Self::Ancestor,
+            between: core::marker::PhantomData<&'a Self::Between>,
+        ) -> Result<&'a Self, crate::Mismatch<&'a Self::Ancestor>>;
+    }
+
+    impl<T, R> Down<Zero, R> for T {
+        type Ancestor = T;
+        type Between = ();
+
+        fn try_from_ancestor(ancestor: T) -> Result<T, crate::Mismatch<T>> {
+            Ok(ancestor)
+        }
+
+        fn try_from_ancestor_ref<'a>(
+            ancestor: &'a T,
+            _: core::marker::PhantomData<&'a ()>,
+        ) -> Result<&'a T, crate::Mismatch<&'a T>> {
+            Ok(ancestor)
+        }
+    }
+
+    impl<T, N, R> Down<Succ<N>, R> for T
+    where
+        T: crate::EnumTree<R>
+            + TryFrom<T::P, Error = crate::Mismatch<T::P>>
+            + crate::TryFromParentRef<T::P>,
+        T::P: Down<N, R>,
+    {
+        type Ancestor = <T::P as Down<N, R>>::Ancestor;
+        type Between = (T::P, <T::P as Down<N, R>>::Between);
+
+        fn try_from_ancestor(
+            ancestor: Self::Ancestor,
+        ) -> Result<T, crate::Mismatch<Self::Ancestor>> {
+            if let Err(mismatch) =
+                <T as Down<Succ<N>, R>>::try_from_ancestor_ref(&ancestor, core::marker::PhantomData)
+            {
+                let (expected, found) = (mismatch.expected, mismatch.found);
+                return Err(crate::Mismatch::new(ancestor, expected, found));
+            }
+            match T::P::try_from_ancestor(ancestor).map(T::try_from) {
+                Ok(Ok(node)) => Ok(node),
+                _ => unreachable!("`TryFrom` disagrees with `TryFromParentRef`"),
+            }
+        }
+
+        fn try_from_ancestor_ref<'a>(
+            ancestor: &'a Self::Ancestor,
+            _: core::marker::PhantomData<&'a Self::Between>,
+        ) -> Result<&'a T, crate::Mismatch<&'a Self::Ancestor>> {
+            let p =
+                <T::P as Down<N, R>>::try_from_ancestor_ref(ancestor, core::marker::PhantomData)?;
+            T::try_from_parent_ref(p)
+                .map_err(|m| crate::Mismatch::new(ancestor, m.expected, m.found))
+        }
+    }
+
+    /// Extracts the child `C` of `Self` from the root `R`.
+    ///
+    /// The root's derive implements it for each child it wraps, and inner nodes for all their
+    /// children at once, through `from_root` and their `TryFrom` impls. Each node's `Ancestry`
+    /// goes through its parents' impls, which keeps the root's direct children from getting a
+    /// second `TryFrom<R>`.
+    pub trait ChildFromRoot<R, C> {
+        fn child_from_root(root: R) -> Result<C, crate::Mismatch<R>>;
+    }
+
+    /// `ChildFromRoot` for an inner node `P`: checks the way down on a borrow, so that a
+    /// mismatch hands the root back.
+    pub fn child_from_root<R, P, C>(root: R) -> Result<C, crate::Mismatch<R>>
+    where
+        P: crate::TryFromEnumTreeRoot<R> + crate::TryFromEnumTreeRootRef<R>,
+        C: TryFrom<P, Error = crate::Mismatch<P>> + crate::TryFromParentRef<P>,
+    {
+        if let Err(mismatch) = child_from_root_ref::<R, P, C>(&root) {
+            let (expected, found) = (mismatch.expected, mismatch.found);
+            return Err(crate::Mismatch::new(root, expected, found));
+        }
+        match P::from_root(root).map(C::try_from) {
+            Some(Ok(node)) => Ok(node),
+            _ => unreachable!("`TryFrom` disagrees with `TryFromParentRef`"),
+        }
+    }
+
+    /// Borrows the child `C` of `P` inside the root `R`.
+    pub fn child_from_root_ref<'a, R, P, C>(root: &'a R) -> Result<&'a C, crate::Mismatch<&'a R>>
+    where
+        P: crate::TryFromEnumTreeRootRef<R> + 'a,
+        C: crate::TryFromParentRef<P>,
+    {
+        let p = P::try_from_root_ref(root)?;
+        C::try_from_parent_ref(p).map_err(|m| crate::Mismatch::new(root, m.expected, m.found))
+    }
+
+    /// Mutably borrows the child `C` of `P` inside the root `R`.
+    pub fn child_from_root_mut<'a, R, P, C>(root: &'a mut R) -> Option<&'a mut C>
+    where
+        P: crate::TryFromEnumTreeRootRef<R> + 'a,
+        C: crate::TryFromParentRef<P>,
+    {
+        let p = P::from_root_mut(root)?;
+        C::try_from_parent_mut(p).ok()
+    }
+
+    /// The place of `Self` in the tree rooted at `R`, generated once per node and tree: its depth,
+    /// which the conversions to and from ancestors are resolved by, and its extraction from the
+    /// root under whichever of its parents holds it, trying them in declaration order.
+    ///
+    /// Nodes below the root go through the `ChildFromRoot` impls of their parents or their
+    /// `TryFrom` impls; the root, at depth `Zero`, is extracted from itself. The root conversions
+    /// are built on it.
+    pub trait Ancestry<R>: Sized {
+        /// `Zero` for the root, and `Succ` of the depth of its `EnumTree::P` for any other node.
+        type Depth;
+
+        /// The node and the path of the parent it was under. A root holding it under none of
+        /// them is handed back with the mismatch on the path through the first parent.
+        fn from_parents(root: R) -> Result<(Self, &'static crate::NodePath), crate::Mismatch<R>>;
+        fn from_parents_ref(root: &R) -> Result<&Self, crate::Mismatch<&R>>;
+        fn from_parents_mut(root: &mut R) -> Option<&mut Self>;
+
+        /// The node and the variant of the parent it was under holding it. The root is held in
+        /// none, and `TryFromEnumTreeRootAt` leaves it out.
+        fn from_parents_at(root: R) -> Option<(Self, &'static str)>;
+    }
+
+    /// Like `Ancestry`, collecting the context fields on the way down. Implemented apart, so
+    /// that parents giving a node different context types only lose it this conversion.
+    pub trait FromParentsWithContext<R>: Sized {
+        type Context;
+        fn from_parents_with_context(root: R) -> Option<(Self, Self::Context)>;
+    }
+
+    /// The variant of `P` holding a `C` inside the root `R`, if it holds one.
+    pub fn position_in_root<R, P, C>(root: &R) -> Option<&'static str>
+    where
+        P: ParentOf<C> + crate::TryFromEnumTreeRootRef<R>,
+        C: crate::TryFromParentRef<P>,
+    {
+        let p = P::from_root_ref(root)?;
+        C::try_from_parent_ref(p).ok()?;
+        Some(p.position())
+    }
+
+    /// A node `C` found under its parent `P` in the root `R`, with the context on the way.
+    pub type WithContext<R, P, C> = (
+        C,
+        (
+            <P as crate::TryFromEnumTreeRootWithContext<R>>::Context,
+            <C as crate::TryFromParentWithContext<P>>::Context,
+        ),
+    );
+
+    /// Extracts the child `C` of `P` from the root `R` with the context of every variant on the
+    /// way.
+    pub fn child_from_root_with_context<R, P, C>(root: R) -> Option<WithContext<R, P, C>>
+    where
+        P: crate::TryFromEnumTreeRootWithContext<R>,
+        C: crate::TryFromParentWithContext<P>,
+    {
+        let (p, p_context) = P::from_root_with_context(root)?;
+        let (node, context) = C::try_from_parent_with_context(p)?;
+        Some((node, (p_context, context)))
+    }
+
+    /// Implemented by the root and inner nodes, naming the variant a value holds.
+    pub trait VariantName {
+        fn variant_name(&self) -> &'static str;
+    }
+
+    /// Checks at compile time that `C` declares `P` as its parent in the tree rooted at `R`.
+    pub const fn assert_child_of<C: ChildOf<P, R>, P, R>() {}
+
+    /// Checks at compile time that the root of a child of `P` can be inferred from `P`.
+    pub const fn assert_single_root<P: SingleRoot>()
+    where
+        P::Root: SingleRootOf<P>,
+    {
+    }
+
+    /// Checks at compile time that parents `P` and `Q` of a child inferring its root are in the
+    /// same tree.
+    pub const fn assert_same_root<P, Q: SameRoot<P>>() {}
+
+    /// Checks at compile time that parent `P` wraps child `C` in the variant named `variant`.
+    pub const fn assert_parent_variant<P: ParentOf<C>, C>(variant: &str, message: &str) {
+        if find_parent_variant::<P, C>(variant).is_none() {
+            panic!("{}", message);
+        }
+    }
+
+    /// The variant of parent `P` named `variant` which wraps child `C`, failing with `message`
+    /// if there is none. Gives a generic child's path the name checked against its parent.
+    pub const fn parent_variant<P: ParentOf<C>, C>(variant: &str, message: &str) -> &'static str {
+        match find_parent_variant::<P, C>(variant) {
+            Some(variant) => variant,
+            None => panic!("{}", message),
+        }
+    }
+
+    const fn find_parent_variant<P: ParentOf<C>, C>(variant: &str) -> Option<&'static str> {
+        let mut i = 0;
+        while i < P::VARIANTS.len() {
+            if str_eq(P::VARIANTS[i], variant) {
+                return Some(P::VARIANTS[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    const fn str_eq(a: &str, b: &str) -> bool {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
     }
 }
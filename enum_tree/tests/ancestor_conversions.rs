@@ -0,0 +1,116 @@
+use enum_tree::{EnumTree, ToEnumTreeAncestor, TryFromEnumTreeAncestor};
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum AppAction {
+    Menu(Menu),
+    Quit,
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+pub enum Menu {
+    Settings(Box<Settings>),
+    Close,
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_inner(Menu, AppAction)]
+pub enum Settings {
+    Sound(Sound),
+    Reset,
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_leaf(Settings, AppAction)]
+pub enum Sound {
+    Toggle,
+}
+
+#[test]
+fn test_to_ancestor_stops_at_any_level() {
+    assert_eq!(
+        Sound::Toggle.to_ancestor::<Settings>(),
+        Settings::Sound(Sound::Toggle)
+    );
+    assert_eq!(
+        Sound::Toggle.to_ancestor::<Menu>(),
+        Menu::Settings(Box::new(Settings::Sound(Sound::Toggle)))
+    );
+    assert_eq!(
+        Settings::Reset.to_ancestor::<AppAction>(),
+        AppAction::Menu(Menu::Settings(Box::new(Settings::Reset)))
+    );
+    assert_eq!(Menu::Close.to_ancestor::<Menu>(), Menu::Close);
+}
+
+#[test]
+fn test_try_from_ancestor_at_any_level() {
+    let menu = Menu::Settings(Box::new(Settings::Sound(Sound::Toggle)));
+    assert_eq!(Sound::try_from_ancestor(menu), Ok(Sound::Toggle));
+
+    let settings = Settings::Sound(Sound::Toggle);
+    assert_eq!(Sound::try_from_ancestor(settings), Ok(Sound::Toggle));
+
+    let root = Sound::Toggle.to_ancestor::<AppAction>();
+    assert_eq!(
+        Settings::try_from_ancestor(root),
+        Ok(Settings::Sound(Sound::Toggle))
+    );
+}
+
+#[test]
+fn test_try_from_ancestor_hands_the_ancestor_back() {
+    let mismatch = Sound::try_from_ancestor(Menu::Close).unwrap_err();
+    assert_eq!(
+        (mismatch.expected(), mismatch.found()),
+        ("Settings", "Close")
+    );
+    assert_eq!(mismatch.into_inner(), Menu::Close);
+
+    let menu = Settings::Reset.to_ancestor::<Menu>();
+    let mismatch = Sound::try_from_ancestor(menu).unwrap_err();
+    assert_eq!((mismatch.expected(), mismatch.found()), ("Sound", "Reset"));
+    assert_eq!(
+        mismatch.into_inner(),
+        Menu::Settings(Box::new(Settings::Reset))
+    );
+}
+
+mod top_down {
+    use enum_tree::{EnumTree, ToEnumTreeAncestor, TryFromEnumTreeAncestor};
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    #[enum_tree_root(top_down)]
+    pub enum AppAction {
+        Menu(Menu),
+    }
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    pub enum Menu {
+        Settings(Settings),
+        Close,
+    }
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    pub enum Settings {
+        Reset,
+    }
+
+    #[test]
+    fn test_top_down_ancestor_conversions() {
+        assert_eq!(
+            Settings::Reset.to_ancestor::<Menu>(),
+            Menu::Settings(Settings::Reset)
+        );
+        assert_eq!(
+            Settings::Reset.to_ancestor::<AppAction>(),
+            AppAction::Menu(Menu::Settings(Settings::Reset))
+        );
+
+        let root = AppAction::Menu(Menu::Settings(Settings::Reset));
+        assert_eq!(Settings::try_from_ancestor(root), Ok(Settings::Reset));
+        let mismatch = Settings::try_from_ancestor(Menu::Close).unwrap_err();
+        assert_eq!(mismatch.into_inner(), Menu::Close);
+    }
+}
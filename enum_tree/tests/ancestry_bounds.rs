@@ -0,0 +1,85 @@
+use enum_tree::{AncestorOf, DescendantOf, EnumTree, ToEnumTreeAncestor};
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum AppAction {
+    Menu(Menu),
+    Audio(Audio),
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+pub enum Menu {
+    Settings(Settings),
+    Close,
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_inner(Menu, AppAction)]
+pub enum Settings {
+    Sound(Sound),
+    Reset,
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_leaf(Settings, AppAction)]
+pub enum Sound {
+    Toggle,
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_leaf(AppAction, AppAction)]
+pub enum Audio {
+    Mute,
+}
+
+fn assert_descendant<T: DescendantOf<A, R>, A, R>() {}
+
+// Generic over anything under `Menu`, without spelling out the chain of parents.
+fn open_menu<T: DescendantOf<Menu, AppAction>>(action: T) -> Menu {
+    action.to_ancestor()
+}
+
+fn find_in_menu<T: DescendantOf<Menu, AppAction>>(menu: Menu) -> Option<T> {
+    T::try_from_ancestor(menu).ok()
+}
+
+#[test]
+fn test_descendants_at_any_depth() {
+    assert_descendant::<Settings, Menu, AppAction>();
+    assert_descendant::<Sound, Menu, AppAction>();
+    assert_descendant::<Sound, Settings, AppAction>();
+    assert_descendant::<Sound, AppAction, AppAction>();
+    assert_descendant::<Audio, AppAction, AppAction>();
+}
+
+#[test]
+fn test_generic_conversions_under_an_ancestor() {
+    assert_eq!(
+        open_menu(Sound::Toggle),
+        Menu::Settings(Settings::Sound(Sound::Toggle))
+    );
+    assert_eq!(open_menu(Settings::Reset), Menu::Settings(Settings::Reset));
+
+    let menu = Menu::Settings(Settings::Sound(Sound::Toggle));
+    assert_eq!(find_in_menu::<Sound>(menu), Some(Sound::Toggle));
+    assert_eq!(find_in_menu::<Settings>(Menu::Close), None);
+}
+
+#[test]
+fn test_ancestor_of_converts_both_ways() {
+    fn round_trip<A: AncestorOf<D, AppAction>, D>(descendant: D) -> D {
+        A::from_descendant(descendant)
+            .try_into_descendant()
+            .ok()
+            .unwrap()
+    }
+    assert_eq!(round_trip::<Menu, _>(Sound::Toggle), Sound::Toggle);
+    assert_eq!(round_trip::<AppAction, _>(Audio::Mute), Audio::Mute);
+
+    let mismatch = <AppAction as AncestorOf<Sound, AppAction>>::try_into_descendant(
+        AppAction::Audio(Audio::Mute),
+    )
+    .unwrap_err();
+    assert_eq!((mismatch.expected(), mismatch.found()), ("Menu", "Audio"));
+}
@@ -0,0 +1,136 @@
+use std::rc::Rc;
+
+use enum_tree::{EnumTree, ToEnumTreeRoot, TryFromEnumTreeRootRef};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum AppAction {
+    Editor(Box<Editor>),
+    Shared(Shared),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum Plugin {
+    Shared(Shared),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+pub enum Editor {
+    Buffer {
+        id: u32,
+        #[enum_tree(child)]
+        action: BufferAction,
+    },
+    Primary(Rc<Cursor>),
+    Secondary(Rc<Cursor>),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Editor, AppAction, variant = Buffer)]
+pub enum BufferAction {
+    Insert { text: String },
+    Save,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Editor, AppAction, variant = Primary)]
+pub enum Cursor {
+    Move { line: u32 },
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+#[enum_tree_inner(Plugin, Plugin)]
+pub enum Shared {
+    Counter(Counter),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Shared, AppAction)]
+#[enum_tree_leaf(Shared, Plugin)]
+pub struct Counter {
+    count: u32,
+}
+
+#[test]
+fn test_from_root_ref_reads_without_cloning() {
+    let queue = [
+        AppAction::Editor(Box::new(Editor::Buffer {
+            id: 1,
+            action: BufferAction::Save,
+        })),
+        Counter { count: 2 }.to_root(),
+    ];
+    let saves = queue
+        .iter()
+        .filter(|action| BufferAction::from_root_ref(action) == Some(&BufferAction::Save))
+        .count();
+    assert_eq!(saves, 1);
+    assert_eq!(
+        Counter::from_root_ref(&queue[1]),
+        Some(&Counter { count: 2 })
+    );
+    assert_eq!(Counter::from_root_ref(&queue[0]), None);
+    assert!(Editor::from_root_ref(&queue[0]).is_some());
+    assert_eq!(AppAction::from_root_ref(&queue[1]), Some(&queue[1]));
+}
+
+#[test]
+fn test_from_root_mut_edits_in_place() {
+    let mut root = AppAction::Editor(Box::new(Editor::Buffer {
+        id: 1,
+        action: BufferAction::Insert {
+            text: String::from("a"),
+        },
+    }));
+    if let Some(BufferAction::Insert { text }) = BufferAction::from_root_mut(&mut root) {
+        text.push('b');
+    }
+    assert_eq!(
+        root,
+        AppAction::Editor(Box::new(Editor::Buffer {
+            id: 1,
+            action: BufferAction::Insert {
+                text: String::from("ab"),
+            },
+        }))
+    );
+    assert_eq!(Cursor::from_root_mut(&mut root), None);
+}
+
+#[test]
+fn test_borrow_through_every_tree() {
+    let mut app: AppAction = Counter { count: 1 }.to_root();
+    let mut plugin: Plugin = Counter { count: 1 }.to_root();
+    <Counter as TryFromEnumTreeRootRef<AppAction>>::from_root_mut(&mut app)
+        .unwrap()
+        .count += 1;
+    <Counter as TryFromEnumTreeRootRef<Plugin>>::from_root_mut(&mut plugin)
+        .unwrap()
+        .count += 2;
+    assert_eq!(app, Counter { count: 2 }.to_root());
+    assert_eq!(plugin, Counter { count: 3 }.to_root());
+}
+
+#[test]
+fn test_borrow_shared_slots_in_any_position() {
+    let cursor = Rc::new(Cursor::Move { line: 1 });
+    let mut root = AppAction::Editor(Box::new(Editor::Secondary(Rc::clone(&cursor))));
+    assert_eq!(
+        Cursor::from_root_ref(&root),
+        Some(&Cursor::Move { line: 1 })
+    );
+
+    // A shared child is cloned before it is changed, so other holders keep the old value.
+    let Some(Cursor::Move { line }) = Cursor::from_root_mut(&mut root) else {
+        panic!("cursor not found");
+    };
+    *line = 2;
+    assert_eq!(
+        Cursor::from_root_ref(&root),
+        Some(&Cursor::Move { line: 2 })
+    );
+    assert_eq!(*cursor, Cursor::Move { line: 1 });
+}
@@ -0,0 +1,98 @@
+use enum_tree::{
+    EnumTree, ToEnumTreeRoot, TryFromEnumTreeRoot, TryFromEnumTreeRootWithContext,
+    TryFromParentWithContext,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BufferId(u32);
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum RootAction {
+    Editor(Editor),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum Editor {
+    Buffer {
+        id: BufferId,
+        #[enum_tree(child)]
+        action: BufferAction,
+    },
+    Split(u8, #[enum_tree(child)] Box<Pane>),
+    Palette(Palette),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Editor, RootAction, variant = Buffer)]
+pub enum BufferAction {
+    Save,
+    Close,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Editor, RootAction, variant = Split)]
+pub enum Pane {
+    Focus,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Editor, RootAction)]
+pub enum Palette {
+    Open,
+}
+
+fn buffer(id: u32, action: BufferAction) -> RootAction {
+    RootAction::Editor(Editor::Buffer {
+        id: BufferId(id),
+        action,
+    })
+}
+
+#[test]
+fn test_from_root_skips_context() {
+    assert_eq!(
+        BufferAction::from_root(buffer(7, BufferAction::Save)),
+        Some(BufferAction::Save)
+    );
+    assert_eq!(Pane::from_root(buffer(7, BufferAction::Save)), None);
+}
+
+#[test]
+fn test_from_parent_with_context() {
+    let editor = Editor::Split(2, Box::new(Pane::Focus));
+    assert_eq!(
+        Pane::try_from_parent_with_context(editor),
+        Some((Pane::Focus, (2,)))
+    );
+    assert_eq!(
+        Pane::try_from_parent_with_context(Editor::Palette(Palette::Open)),
+        None
+    );
+}
+
+#[test]
+fn test_from_root_with_context() {
+    assert_eq!(
+        BufferAction::from_root_with_context(buffer(7, BufferAction::Close)),
+        Some((BufferAction::Close, (((), ()), (BufferId(7),))))
+    );
+    assert_eq!(
+        Palette::from_root_with_context(RootAction::Editor(Editor::Palette(Palette::Open))),
+        Some((Palette::Open, (((), ()), ())))
+    );
+    assert_eq!(
+        Palette::from_root_with_context(buffer(7, BufferAction::Close)),
+        None
+    );
+}
+
+#[test]
+fn test_to_root_without_context() {
+    // Only children wrapped without context convert up.
+    assert_eq!(
+        Palette::Open.to_root(),
+        RootAction::Editor(Editor::Palette(Palette::Open))
+    );
+}
@@ -0,0 +1,102 @@
+use enum_tree::TryFromEnumTreeAncestor;
+
+// Only `enum_tree!` sees the whole tree, so only it converts descendants into every ancestor.
+enum_tree::enum_tree! {
+    #[derive(Clone, Debug, PartialEq)]
+    pub AppAction {
+        Menu {
+            Settings {
+                Sound { Toggle, Volume { level: u8 } },
+                Reset,
+            },
+            Close,
+        },
+        Quit { Now },
+    }
+}
+
+fn dispatch(action: impl Into<AppAction>) -> AppAction {
+    action.into()
+}
+
+#[test]
+fn test_into_root_at_any_depth() {
+    let toggle = AppAction::Menu(Menu::Settings(Settings::Sound(Sound::Toggle)));
+    assert_eq!(dispatch(Sound::Toggle), toggle);
+    assert_eq!(
+        dispatch(Settings::Reset),
+        AppAction::Menu(Menu::Settings(Settings::Reset))
+    );
+    assert_eq!(dispatch(Menu::Close), AppAction::Menu(Menu::Close));
+    assert_eq!(dispatch(Quit::Now), AppAction::Quit(Quit::Now));
+    assert_eq!(dispatch(toggle.clone()), toggle);
+}
+
+#[test]
+fn test_into_inner_ancestor() {
+    assert_eq!(
+        Menu::from(Sound::Volume { level: 3 }),
+        Menu::Settings(Settings::Sound(Sound::Volume { level: 3 }))
+    );
+    assert_eq!(
+        Settings::from(Sound::Toggle),
+        Settings::Sound(Sound::Toggle)
+    );
+}
+
+#[test]
+fn test_question_mark_lifts_into_root() {
+    fn check(level: u8) -> Result<u8, AppAction> {
+        if level > 10 {
+            Err(Sound::Volume { level })?;
+        }
+        Ok(level)
+    }
+    assert_eq!(check(3), Ok(3));
+    assert_eq!(
+        check(11),
+        Err(AppAction::Menu(Menu::Settings(Settings::Sound(
+            Sound::Volume { level: 11 }
+        ))))
+    );
+}
+
+#[test]
+fn test_try_from_root_at_any_depth() {
+    let root = AppAction::Menu(Menu::Settings(Settings::Sound(Sound::Toggle)));
+    assert_eq!(Sound::try_from(root.clone()), Ok(Sound::Toggle));
+    assert_eq!(
+        Settings::try_from(root.clone()),
+        Ok(Settings::Sound(Sound::Toggle))
+    );
+    assert_eq!(
+        Menu::try_from(root.clone()),
+        Ok(Menu::Settings(Settings::Sound(Sound::Toggle)))
+    );
+    assert_eq!(Quit::try_from(root).map_err(|m| m.found()), Err("Menu"));
+
+    let root: AppAction = Quit::Now.into();
+    assert_eq!(Quit::try_from(root.clone()), Ok(Quit::Now));
+    assert_eq!(Sound::try_from(root).map_err(|m| m.found()), Err("Quit"));
+}
+
+#[test]
+fn test_try_from_inner_ancestor() {
+    let menu = Menu::from(Sound::Volume { level: 3 });
+    assert_eq!(
+        Sound::try_from(menu.clone()),
+        Ok(Sound::Volume { level: 3 })
+    );
+    assert_eq!(
+        Sound::try_from(Menu::Settings(Settings::Reset)).map_err(|m| (m.expected(), m.found())),
+        Err(("Sound", "Reset"))
+    );
+    assert_eq!(
+        Sound::try_from(Menu::Close).map_err(|m| m.into_inner()),
+        Err(Menu::Close)
+    );
+    assert_eq!(
+        Sound::try_from_ancestor(menu),
+        Ok(Sound::Volume { level: 3 })
+    );
+}
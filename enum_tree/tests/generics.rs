@@ -0,0 +1,64 @@
+use enum_tree::{EnumTree, EnumTreeNodePath, ToEnumTreeRoot, TryFromEnumTreeRoot};
+
+pub trait Id: Clone + PartialEq {}
+impl Id for u32 {}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum App<'a, T: Id> {
+    Menu(Menu<'a, T>),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(App<'a, T>, App<'a, T>)]
+pub enum Menu<'a, T: Id> {
+    Payload(Payload<'a, T>),
+    Prefs(Settings<'a, T>),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Menu<'a, T>, App<'a, T>)]
+pub enum Payload<'a, T>
+where
+    T: Id,
+{
+    Text { text: &'a str },
+    Select { id: T },
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Menu<'a, T>, App<'a, T>, variant = Prefs)]
+pub enum Settings<'a, T: Id> {
+    Rename { id: T, name: &'a str },
+}
+
+#[test]
+fn test_to_root_generic() {
+    let text = String::from("hello");
+    let root: App<'_, u32> = Payload::Text { text: &text }.to_root();
+    assert_eq!(
+        root,
+        App::Menu(Menu::Payload(Payload::Text { text: "hello" }))
+    );
+}
+
+#[test]
+fn test_from_root_generic() {
+    let root: App<'static, u32> = App::Menu(Menu::Payload(Payload::Select { id: 7 }));
+    let payload = Payload::from_root(root).unwrap();
+    assert_eq!(payload, Payload::Select { id: 7 });
+}
+
+#[test]
+fn test_renamed_variant_generic() {
+    let root: App<'static, u32> = Settings::Rename { id: 1, name: "a" }.to_root();
+    assert_eq!(
+        root,
+        App::Menu(Menu::Prefs(Settings::Rename { id: 1, name: "a" }))
+    );
+    assert!(Settings::from_root(root).is_some());
+    assert_eq!(
+        <Settings<'static, u32> as EnumTreeNodePath<App<'static, u32>>>::PATH.to_string(),
+        "Menu/Prefs"
+    );
+}
@@ -0,0 +1,203 @@
+//! A tree of 601 nodes: 24 inner nodes holding 24 leaves each. CI times the build of this test,
+//! since generated impls that the trait solver has to weigh against each other make compile
+//! time grow much faster than the tree.
+
+use enum_tree::{
+    EnumTreeNodePath, EnumTreeNodes, ToEnumTreeAncestor, ToEnumTreeRoot, TryFromEnumTreeAncestor,
+    TryFromEnumTreeRoot,
+};
+
+enum_tree::enum_tree! {
+    #[derive(Clone, Debug, PartialEq)]
+    pub Root {
+        I0 {
+            L0_0 { A, B }, L0_1 { A, B }, L0_2 { A, B }, L0_3 { A, B }, L0_4 { A, B },
+            L0_5 { A, B }, L0_6 { A, B }, L0_7 { A, B }, L0_8 { A, B }, L0_9 { A, B },
+            L0_10 { A, B }, L0_11 { A, B }, L0_12 { A, B }, L0_13 { A, B }, L0_14 { A, B },
+            L0_15 { A, B }, L0_16 { A, B }, L0_17 { A, B }, L0_18 { A, B }, L0_19 { A, B },
+            L0_20 { A, B }, L0_21 { A, B }, L0_22 { A, B }, L0_23 { A, B },
+        },
+        I1 {
+            L1_0 { A, B }, L1_1 { A, B }, L1_2 { A, B }, L1_3 { A, B }, L1_4 { A, B },
+            L1_5 { A, B }, L1_6 { A, B }, L1_7 { A, B }, L1_8 { A, B }, L1_9 { A, B },
+            L1_10 { A, B }, L1_11 { A, B }, L1_12 { A, B }, L1_13 { A, B }, L1_14 { A, B },
+            L1_15 { A, B }, L1_16 { A, B }, L1_17 { A, B }, L1_18 { A, B }, L1_19 { A, B },
+            L1_20 { A, B }, L1_21 { A, B }, L1_22 { A, B }, L1_23 { A, B },
+        },
+        I2 {
+            L2_0 { A, B }, L2_1 { A, B }, L2_2 { A, B }, L2_3 { A, B }, L2_4 { A, B },
+            L2_5 { A, B }, L2_6 { A, B }, L2_7 { A, B }, L2_8 { A, B }, L2_9 { A, B },
+            L2_10 { A, B }, L2_11 { A, B }, L2_12 { A, B }, L2_13 { A, B }, L2_14 { A, B },
+            L2_15 { A, B }, L2_16 { A, B }, L2_17 { A, B }, L2_18 { A, B }, L2_19 { A, B },
+            L2_20 { A, B }, L2_21 { A, B }, L2_22 { A, B }, L2_23 { A, B },
+        },
+        I3 {
+            L3_0 { A, B }, L3_1 { A, B }, L3_2 { A, B }, L3_3 { A, B }, L3_4 { A, B },
+            L3_5 { A, B }, L3_6 { A, B }, L3_7 { A, B }, L3_8 { A, B }, L3_9 { A, B },
+            L3_10 { A, B }, L3_11 { A, B }, L3_12 { A, B }, L3_13 { A, B }, L3_14 { A, B },
+            L3_15 { A, B }, L3_16 { A, B }, L3_17 { A, B }, L3_18 { A, B }, L3_19 { A, B },
+            L3_20 { A, B }, L3_21 { A, B }, L3_22 { A, B }, L3_23 { A, B },
+        },
+        I4 {
+            L4_0 { A, B }, L4_1 { A, B }, L4_2 { A, B }, L4_3 { A, B }, L4_4 { A, B },
+            L4_5 { A, B }, L4_6 { A, B }, L4_7 { A, B }, L4_8 { A, B }, L4_9 { A, B },
+            L4_10 { A, B }, L4_11 { A, B }, L4_12 { A, B }, L4_13 { A, B }, L4_14 { A, B },
+            L4_15 { A, B }, L4_16 { A, B }, L4_17 { A, B }, L4_18 { A, B }, L4_19 { A, B },
+            L4_20 { A, B }, L4_21 { A, B }, L4_22 { A, B }, L4_23 { A, B },
+        },
+        I5 {
+            L5_0 { A, B }, L5_1 { A, B }, L5_2 { A, B }, L5_3 { A, B }, L5_4 { A, B },
+            L5_5 { A, B }, L5_6 { A, B }, L5_7 { A, B }, L5_8 { A, B }, L5_9 { A, B },
+            L5_10 { A, B }, L5_11 { A, B }, L5_12 { A, B }, L5_13 { A, B }, L5_14 { A, B },
+            L5_15 { A, B }, L5_16 { A, B }, L5_17 { A, B }, L5_18 { A, B }, L5_19 { A, B },
+            L5_20 { A, B }, L5_21 { A, B }, L5_22 { A, B }, L5_23 { A, B },
+        },
+        I6 {
+            L6_0 { A, B }, L6_1 { A, B }, L6_2 { A, B }, L6_3 { A, B }, L6_4 { A, B },
+            L6_5 { A, B }, L6_6 { A, B }, L6_7 { A, B }, L6_8 { A, B }, L6_9 { A, B },
+            L6_10 { A, B }, L6_11 { A, B }, L6_12 { A, B }, L6_13 { A, B }, L6_14 { A, B },
+            L6_15 { A, B }, L6_16 { A, B }, L6_17 { A, B }, L6_18 { A, B }, L6_19 { A, B },
+            L6_20 { A, B }, L6_21 { A, B }, L6_22 { A, B }, L6_23 { A, B },
+        },
+        I7 {
+            L7_0 { A, B }, L7_1 { A, B }, L7_2 { A, B }, L7_3 { A, B }, L7_4 { A, B },
+            L7_5 { A, B }, L7_6 { A, B }, L7_7 { A, B }, L7_8 { A, B }, L7_9 { A, B },
+            L7_10 { A, B }, L7_11 { A, B }, L7_12 { A, B }, L7_13 { A, B }, L7_14 { A, B },
+            L7_15 { A, B }, L7_16 { A, B }, L7_17 { A, B }, L7_18 { A, B }, L7_19 { A, B },
+            L7_20 { A, B }, L7_21 { A, B }, L7_22 { A, B }, L7_23 { A, B },
+        },
+        I8 {
+            L8_0 { A, B }, L8_1 { A, B }, L8_2 { A, B }, L8_3 { A, B }, L8_4 { A, B },
+            L8_5 { A, B }, L8_6 { A, B }, L8_7 { A, B }, L8_8 { A, B }, L8_9 { A, B },
+            L8_10 { A, B }, L8_11 { A, B }, L8_12 { A, B }, L8_13 { A, B }, L8_14 { A, B },
+            L8_15 { A, B }, L8_16 { A, B }, L8_17 { A, B }, L8_18 { A, B }, L8_19 { A, B },
+            L8_20 { A, B }, L8_21 { A, B }, L8_22 { A, B }, L8_23 { A, B },
+        },
+        I9 {
+            L9_0 { A, B }, L9_1 { A, B }, L9_2 { A, B }, L9_3 { A, B }, L9_4 { A, B },
+            L9_5 { A, B }, L9_6 { A, B }, L9_7 { A, B }, L9_8 { A, B }, L9_9 { A, B },
+            L9_10 { A, B }, L9_11 { A, B }, L9_12 { A, B }, L9_13 { A, B }, L9_14 { A, B },
+            L9_15 { A, B }, L9_16 { A, B }, L9_17 { A, B }, L9_18 { A, B }, L9_19 { A, B },
+            L9_20 { A, B }, L9_21 { A, B }, L9_22 { A, B }, L9_23 { A, B },
+        },
+        I10 {
+            L10_0 { A, B }, L10_1 { A, B }, L10_2 { A, B }, L10_3 { A, B }, L10_4 { A, B },
+            L10_5 { A, B }, L10_6 { A, B }, L10_7 { A, B }, L10_8 { A, B }, L10_9 { A, B },
+            L10_10 { A, B }, L10_11 { A, B }, L10_12 { A, B }, L10_13 { A, B }, L10_14 { A, B },
+            L10_15 { A, B }, L10_16 { A, B }, L10_17 { A, B }, L10_18 { A, B }, L10_19 { A, B },
+            L10_20 { A, B }, L10_21 { A, B }, L10_22 { A, B }, L10_23 { A, B },
+        },
+        I11 {
+            L11_0 { A, B }, L11_1 { A, B }, L11_2 { A, B }, L11_3 { A, B }, L11_4 { A, B },
+            L11_5 { A, B }, L11_6 { A, B }, L11_7 { A, B }, L11_8 { A, B }, L11_9 { A, B },
+            L11_10 { A, B }, L11_11 { A, B }, L11_12 { A, B }, L11_13 { A, B }, L11_14 { A, B },
+            L11_15 { A, B }, L11_16 { A, B }, L11_17 { A, B }, L11_18 { A, B }, L11_19 { A, B },
+            L11_20 { A, B }, L11_21 { A, B }, L11_22 { A, B }, L11_23 { A, B },
+        },
+        I12 {
+            L12_0 { A, B }, L12_1 { A, B }, L12_2 { A, B }, L12_3 { A, B }, L12_4 { A, B },
+            L12_5 { A, B }, L12_6 { A, B }, L12_7 { A, B }, L12_8 { A, B }, L12_9 { A, B },
+            L12_10 { A, B }, L12_11 { A, B }, L12_12 { A, B }, L12_13 { A, B }, L12_14 { A, B },
+            L12_15 { A, B }, L12_16 { A, B }, L12_17 { A, B }, L12_18 { A, B }, L12_19 { A, B },
+            L12_20 { A, B }, L12_21 { A, B }, L12_22 { A, B }, L12_23 { A, B },
+        },
+        I13 {
+            L13_0 { A, B }, L13_1 { A, B }, L13_2 { A, B }, L13_3 { A, B }, L13_4 { A, B },
+            L13_5 { A, B }, L13_6 { A, B }, L13_7 { A, B }, L13_8 { A, B }, L13_9 { A, B },
+            L13_10 { A, B }, L13_11 { A, B }, L13_12 { A, B }, L13_13 { A, B }, L13_14 { A, B },
+            L13_15 { A, B }, L13_16 { A, B }, L13_17 { A, B }, L13_18 { A, B }, L13_19 { A, B },
+            L13_20 { A, B }, L13_21 { A, B }, L13_22 { A, B }, L13_23 { A, B },
+        },
+        I14 {
+            L14_0 { A, B }, L14_1 { A, B }, L14_2 { A, B }, L14_3 { A, B }, L14_4 { A, B },
+            L14_5 { A, B }, L14_6 { A, B }, L14_7 { A, B }, L14_8 { A, B }, L14_9 { A, B },
+            L14_10 { A, B }, L14_11 { A, B }, L14_12 { A, B }, L14_13 { A, B }, L14_14 { A, B },
+            L14_15 { A, B }, L14_16 { A, B }, L14_17 { A, B }, L14_18 { A, B }, L14_19 { A, B },
+            L14_20 { A, B }, L14_21 { A, B }, L14_22 { A, B }, L14_23 { A, B },
+        },
+        I15 {
+            L15_0 { A, B }, L15_1 { A, B }, L15_2 { A, B }, L15_3 { A, B }, L15_4 { A, B },
+            L15_5 { A, B }, L15_6 { A, B }, L15_7 { A, B }, L15_8 { A, B }, L15_9 { A, B },
+            L15_10 { A, B }, L15_11 { A, B }, L15_12 { A, B }, L15_13 { A, B }, L15_14 { A, B },
+            L15_15 { A, B }, L15_16 { A, B }, L15_17 { A, B }, L15_18 { A, B }, L15_19 { A, B },
+            L15_20 { A, B }, L15_21 { A, B }, L15_22 { A, B }, L15_23 { A, B },
+        },
+        I16 {
+            L16_0 { A, B }, L16_1 { A, B }, L16_2 { A, B }, L16_3 { A, B }, L16_4 { A, B },
+            L16_5 { A, B }, L16_6 { A, B }, L16_7 { A, B }, L16_8 { A, B }, L16_9 { A, B },
+            L16_10 { A, B }, L16_11 { A, B }, L16_12 { A, B }, L16_13 { A, B }, L16_14 { A, B },
+            L16_15 { A, B }, L16_16 { A, B }, L16_17 { A, B }, L16_18 { A, B }, L16_19 { A, B },
+            L16_20 { A, B }, L16_21 { A, B }, L16_22 { A, B }, L16_23 { A, B },
+        },
+        I17 {
+            L17_0 { A, B }, L17_1 { A, B }, L17_2 { A, B }, L17_3 { A, B }, L17_4 { A, B },
+            L17_5 { A, B }, L17_6 { A, B }, L17_7 { A, B }, L17_8 { A, B }, L17_9 { A, B },
+            L17_10 { A, B }, L17_11 { A, B }, L17_12 { A, B }, L17_13 { A, B }, L17_14 { A, B },
+            L17_15 { A, B }, L17_16 { A, B }, L17_17 { A, B }, L17_18 { A, B }, L17_19 { A, B },
+            L17_20 { A, B }, L17_21 { A, B }, L17_22 { A, B }, L17_23 { A, B },
+        },
+        I18 {
+            L18_0 { A, B }, L18_1 { A, B }, L18_2 { A, B }, L18_3 { A, B }, L18_4 { A, B },
+            L18_5 { A, B }, L18_6 { A, B }, L18_7 { A, B }, L18_8 { A, B }, L18_9 { A, B },
+            L18_10 { A, B }, L18_11 { A, B }, L18_12 { A, B }, L18_13 { A, B }, L18_14 { A, B },
+            L18_15 { A, B }, L18_16 { A, B }, L18_17 { A, B }, L18_18 { A, B }, L18_19 { A, B },
+            L18_20 { A, B }, L18_21 { A, B }, L18_22 { A, B }, L18_23 { A, B },
+        },
+        I19 {
+            L19_0 { A, B }, L19_1 { A, B }, L19_2 { A, B }, L19_3 { A, B }, L19_4 { A, B },
+            L19_5 { A, B }, L19_6 { A, B }, L19_7 { A, B }, L19_8 { A, B }, L19_9 { A, B },
+            L19_10 { A, B }, L19_11 { A, B }, L19_12 { A, B }, L19_13 { A, B }, L19_14 { A, B },
+            L19_15 { A, B }, L19_16 { A, B }, L19_17 { A, B }, L19_18 { A, B }, L19_19 { A, B },
+            L19_20 { A, B }, L19_21 { A, B }, L19_22 { A, B }, L19_23 { A, B },
+        },
+        I20 {
+            L20_0 { A, B }, L20_1 { A, B }, L20_2 { A, B }, L20_3 { A, B }, L20_4 { A, B },
+            L20_5 { A, B }, L20_6 { A, B }, L20_7 { A, B }, L20_8 { A, B }, L20_9 { A, B },
+            L20_10 { A, B }, L20_11 { A, B }, L20_12 { A, B }, L20_13 { A, B }, L20_14 { A, B },
+            L20_15 { A, B }, L20_16 { A, B }, L20_17 { A, B }, L20_18 { A, B }, L20_19 { A, B },
+            L20_20 { A, B }, L20_21 { A, B }, L20_22 { A, B }, L20_23 { A, B },
+        },
+        I21 {
+            L21_0 { A, B }, L21_1 { A, B }, L21_2 { A, B }, L21_3 { A, B }, L21_4 { A, B },
+            L21_5 { A, B }, L21_6 { A, B }, L21_7 { A, B }, L21_8 { A, B }, L21_9 { A, B },
+            L21_10 { A, B }, L21_11 { A, B }, L21_12 { A, B }, L21_13 { A, B }, L21_14 { A, B },
+            L21_15 { A, B }, L21_16 { A, B }, L21_17 { A, B }, L21_18 { A, B }, L21_19 { A, B },
+            L21_20 { A, B }, L21_21 { A, B }, L21_22 { A, B }, L21_23 { A, B },
+        },
+        I22 {
+            L22_0 { A, B }, L22_1 { A, B }, L22_2 { A, B }, L22_3 { A, B }, L22_4 { A, B },
+            L22_5 { A, B }, L22_6 { A, B }, L22_7 { A, B }, L22_8 { A, B }, L22_9 { A, B },
+            L22_10 { A, B }, L22_11 { A, B }, L22_12 { A, B }, L22_13 { A, B }, L22_14 { A, B },
+            L22_15 { A, B }, L22_16 { A, B }, L22_17 { A, B }, L22_18 { A, B }, L22_19 { A, B },
+            L22_20 { A, B }, L22_21 { A, B }, L22_22 { A, B }, L22_23 { A, B },
+        },
+        I23 {
+            L23_0 { A, B }, L23_1 { A, B }, L23_2 { A, B }, L23_3 { A, B }, L23_4 { A, B },
+            L23_5 { A, B }, L23_6 { A, B }, L23_7 { A, B }, L23_8 { A, B }, L23_9 { A, B },
+            L23_10 { A, B }, L23_11 { A, B }, L23_12 { A, B }, L23_13 { A, B }, L23_14 { A, B },
+            L23_15 { A, B }, L23_16 { A, B }, L23_17 { A, B }, L23_18 { A, B }, L23_19 { A, B },
+            L23_20 { A, B }, L23_21 { A, B }, L23_22 { A, B }, L23_23 { A, B },
+        },
+    }
+}
+
+#[test]
+fn test_large_tree_lists_nodes() {
+    assert_eq!(Root::NODES.len(), 601);
+    assert_eq!(
+        <L23_23 as EnumTreeNodePath<Root>>::PATH.to_string(),
+        "I23/L23_23"
+    );
+}
+
+#[test]
+fn test_large_tree_conversions() {
+    let root = L17_5::B.to_root();
+    assert_eq!(root, Root::I17(I17::L17_5(L17_5::B)));
+    assert_eq!(Root::from(L17_5::B), root);
+    assert_eq!(L17_5::from_root(root.clone()), Some(L17_5::B));
+    assert_eq!(L17_6::from_root(root), None);
+
+    let inner = L0_23::A.to_ancestor::<I0>();
+    assert_eq!(L0_23::try_from_ancestor(inner), Ok(L0_23::A));
+}
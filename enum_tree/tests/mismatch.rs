@@ -0,0 +1,141 @@
+use enum_tree::{
+    EnumTree, EnumTreeNodePath, Mismatch, ToEnumTreeRoot, TryFromEnumTreeRoot,
+    TryFromEnumTreeRootRef, TryFromEnumTreeRootVia,
+};
+
+// Nothing in this tree is `Clone`: failed conversions hand the value back instead.
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum AppAction {
+    Menu(Menu),
+    Network(Network),
+    Quit,
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+pub enum Menu {
+    Settings(Settings),
+    Network(Network),
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_leaf(Menu, AppAction)]
+pub enum Settings {
+    ToggleSound,
+}
+
+#[derive(EnumTree, Debug, PartialEq)]
+#[enum_tree_leaf(AppAction, AppAction)]
+#[enum_tree_leaf(Menu, AppAction)]
+pub enum Network {
+    Connect { port: u16 },
+}
+
+#[test]
+fn test_try_from_root_hands_the_root_back() {
+    let root = AppAction::Menu(Menu::Network(Network::Connect { port: 80 }));
+    let mismatch = Settings::try_from_root(root).unwrap_err();
+    assert_eq!(mismatch.expected(), "Settings");
+    assert_eq!(mismatch.found(), "Network");
+    assert_eq!(mismatch.to_string(), "expected `Settings`, found `Network`");
+
+    let root = mismatch.into_inner();
+    assert_eq!(
+        root,
+        AppAction::Menu(Menu::Network(Network::Connect { port: 80 }))
+    );
+    let mismatch = Menu::try_from_root(AppAction::Quit).unwrap_err();
+    assert_eq!((mismatch.expected(), mismatch.found()), ("Menu", "Quit"));
+    assert_eq!(
+        Settings::try_from_root(Settings::ToggleSound.to_root()),
+        Ok(Settings::ToggleSound)
+    );
+}
+
+#[test]
+fn test_try_from_hands_the_parent_back() {
+    let mismatch = Settings::try_from(Menu::Network(Network::Connect { port: 1 })).unwrap_err();
+    assert_eq!(
+        mismatch,
+        Mismatch::new(
+            Menu::Network(Network::Connect { port: 1 }),
+            "Settings",
+            "Network"
+        )
+    );
+
+    // `TryFrom<Root>` reports where the path to the node ends.
+    let mismatch = Settings::try_from(AppAction::Quit).unwrap_err();
+    assert_eq!((mismatch.expected(), mismatch.found()), ("Menu", "Quit"));
+    assert_eq!(*mismatch.value(), AppAction::Quit);
+}
+
+#[test]
+fn test_try_from_root_ref_names_the_mismatch() {
+    let root = AppAction::Network(Network::Connect { port: 1 });
+    let mismatch = Settings::try_from_root_ref(&root).unwrap_err();
+    assert_eq!((mismatch.expected(), mismatch.found()), ("Menu", "Network"));
+    assert!(std::ptr::eq(mismatch.into_inner(), &root));
+}
+
+#[test]
+fn test_from_root_via_needs_no_clone() {
+    let root = AppAction::Menu(Menu::Network(Network::Connect { port: 2 }));
+    assert_eq!(
+        Network::from_root_via(root),
+        Some((
+            Network::Connect { port: 2 },
+            <Menu as EnumTreeNodePath<AppAction>>::PATH
+        ))
+    );
+    assert_eq!(Network::from_root_via(AppAction::Quit), None);
+}
+
+#[test]
+fn test_mismatch_is_an_error() {
+    fn extract(root: AppAction) -> Result<Settings, Box<dyn std::error::Error>> {
+        Ok(Settings::try_from_root(root)?)
+    }
+    let error = extract(AppAction::Quit).unwrap_err();
+    assert_eq!(error.to_string(), "expected `Menu`, found `Quit`");
+}
+
+mod renamed {
+    use enum_tree::{EnumTree, TryFromEnumTreeRoot};
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    #[enum_tree_root]
+    pub enum AppAction {
+        Menu(MenuAction),
+        Quit,
+    }
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    #[enum_tree_inner(AppAction, AppAction, variant = Menu)]
+    pub enum MenuAction {
+        Settings(SettingsAction),
+        Close,
+    }
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    #[enum_tree_leaf(MenuAction, AppAction, variant = Settings)]
+    pub enum SettingsAction {
+        ToggleSound,
+    }
+
+    #[test]
+    fn test_mismatch_names_variants_not_types() {
+        let mismatch =
+            SettingsAction::try_from_root(AppAction::Menu(MenuAction::Close)).unwrap_err();
+        assert_eq!(mismatch.to_string(), "expected `Settings`, found `Close`");
+        let mismatch = SettingsAction::try_from(AppAction::Quit).unwrap_err();
+        assert_eq!((mismatch.expected(), mismatch.found()), ("Menu", "Quit"));
+        assert_eq!(
+            SettingsAction::try_from_root(AppAction::Menu(MenuAction::Settings(
+                SettingsAction::ToggleSound
+            ))),
+            Ok(SettingsAction::ToggleSound)
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use enum_tree::{EnumTree, ToEnumTreeRoot, TryFromEnumTreeRoot};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum RootAction {
+    Menu(Menu),
+    Exit,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum Menu {
+    Settings(Settings),
+    Quit,
+    Resize { w: u32 },
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Menu, RootAction)]
+pub enum Settings {
+    ToggleSound,
+}
+
+#[test]
+fn test_own_leaf_variants_round_trip() {
+    let root = Menu::Resize { w: 80 }.to_root();
+    assert_eq!(root, RootAction::Menu(Menu::Resize { w: 80 }));
+    assert_eq!(Menu::from_root(root.clone()), Some(Menu::Resize { w: 80 }));
+    assert_eq!(Settings::from_root(root), None);
+
+    assert_eq!(Menu::from_root(RootAction::Exit), None);
+    assert_eq!(Settings::from_root(Menu::Quit.to_root()), None);
+}
+
+#[test]
+fn test_children_next_to_leaf_variants() {
+    let root = Settings::ToggleSound.to_root();
+    assert_eq!(
+        root,
+        RootAction::Menu(Menu::Settings(Settings::ToggleSound))
+    );
+    assert_eq!(Settings::from_root(root), Some(Settings::ToggleSound));
+}
@@ -0,0 +1,65 @@
+use enum_tree::{
+    EnumTree, ToEnumTreeRoot, ToEnumTreeRootAt, TryFromEnumTreeRoot, TryFromEnumTreeRootAt,
+};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum RootAction {
+    Toolbar(Toolbar),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum Toolbar {
+    Primary(Button),
+    Secondary(Box<Button>),
+    Search(Search),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Toolbar, RootAction, variant = Primary)]
+pub enum Button {
+    Ok,
+    Cancel,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Toolbar, RootAction)]
+pub enum Search {
+    Open,
+}
+
+#[test]
+fn test_to_root_at_position() {
+    assert_eq!(
+        Button::Ok.to_root_at(Toolbar::Primary),
+        RootAction::Toolbar(Toolbar::Primary(Button::Ok))
+    );
+    assert_eq!(
+        Button::Cancel.to_root_at(|b| Toolbar::Secondary(Box::new(b))),
+        RootAction::Toolbar(Toolbar::Secondary(Box::new(Button::Cancel)))
+    );
+    // Children with a single position still convert without naming it.
+    assert_eq!(
+        Search::Open.to_root(),
+        RootAction::Toolbar(Toolbar::Search(Search::Open))
+    );
+}
+
+#[test]
+fn test_from_root_at_reports_position() {
+    let primary = Button::Ok.to_root_at(Toolbar::Primary);
+    let secondary = Button::Cancel.to_root_at(|b| Toolbar::Secondary(Box::new(b)));
+
+    assert_eq!(Button::from_root(primary.clone()), Some(Button::Ok));
+    assert_eq!(Button::from_root_at(primary), Some((Button::Ok, "Primary")));
+    assert_eq!(
+        Button::from_root_at(secondary),
+        Some((Button::Cancel, "Secondary"))
+    );
+    assert_eq!(Button::from_root_at(Search::Open.to_root()), None);
+    assert_eq!(
+        Search::from_root_at(Search::Open.to_root()),
+        Some((Search::Open, "Search"))
+    );
+}
@@ -0,0 +1,104 @@
+use enum_tree::{
+    EnumTree, EnumTreeInner, EnumTreeNodePath, EnumTreeRoot, ToEnumTreeAncestor, ToEnumTreeRoot,
+    TryFromEnumTreeAncestor, TryFromEnumTreeRoot,
+};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum AppRoot {
+    Plugin(PluginAction),
+}
+
+/// Root of the plugin's own tree, mounted under `AppRoot` by the application.
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+#[enum_tree_inner(AppRoot, AppRoot, variant = Plugin)]
+pub enum PluginAction {
+    Tool(Tool),
+    Layer(Layer),
+}
+
+// Listing the outer root as well is still accepted.
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(PluginAction, PluginAction)]
+#[enum_tree_inner(PluginAction, AppRoot)]
+pub enum Tool {
+    Brush(Brush),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Tool, PluginAction)]
+pub enum Brush {
+    Resize { size: u8 },
+}
+
+// The rest of the plugin tree only knows about the plugin root.
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(PluginAction, PluginAction)]
+pub enum Layer {
+    Opacity(Opacity),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree(kind = leaf, parent = Layer)]
+pub enum Opacity {
+    Set { percent: u8 },
+}
+
+fn assert_root<T: EnumTreeRoot<R>, R>() {}
+fn assert_inner<T: EnumTreeInner<R>, R>() {}
+
+#[test]
+fn test_root_is_inner_of_outer_tree() {
+    assert_root::<PluginAction, PluginAction>();
+    assert_inner::<PluginAction, AppRoot>();
+    assert_inner::<Layer, AppRoot>();
+}
+
+#[test]
+fn test_to_root_through_mounted_root() {
+    let brush = Brush::Resize { size: 3 };
+
+    let plugin: PluginAction = brush.clone().to_root();
+    assert_eq!(plugin, PluginAction::Tool(Tool::Brush(brush.clone())));
+
+    let app: AppRoot = brush.clone().to_root();
+    assert_eq!(app, AppRoot::Plugin(PluginAction::Tool(Tool::Brush(brush))));
+
+    let opacity = Opacity::Set { percent: 50 };
+    let app: AppRoot = opacity.clone().to_root();
+    assert_eq!(
+        app,
+        AppRoot::Plugin(PluginAction::Layer(Layer::Opacity(opacity)))
+    );
+}
+
+#[test]
+fn test_from_root_through_mounted_root() {
+    let app = AppRoot::Plugin(PluginAction::Tool(Tool::Brush(Brush::Resize { size: 3 })));
+
+    let brush = <Brush as TryFromEnumTreeRoot<AppRoot>>::from_root(app.clone()).unwrap();
+    assert_eq!(brush, Brush::Resize { size: 3 });
+
+    let plugin = <PluginAction as TryFromEnumTreeRoot<AppRoot>>::from_root(app.clone()).unwrap();
+    assert_eq!(plugin, PluginAction::Tool(Tool::Brush(brush)));
+
+    assert_eq!(
+        <Opacity as TryFromEnumTreeRoot<AppRoot>>::from_root(app),
+        None
+    );
+}
+
+#[test]
+fn test_outer_tree_through_mounted_root() {
+    let opacity = Opacity::Set { percent: 50 };
+    let app = ToEnumTreeAncestor::<AppRoot>::to_ancestor::<AppRoot>(opacity.clone());
+    assert_eq!(
+        TryFromEnumTreeAncestor::<AppRoot>::try_from_ancestor(app),
+        Ok(opacity)
+    );
+    assert_eq!(
+        <Opacity as EnumTreeNodePath<AppRoot>>::PATH.to_string(),
+        "Plugin/Layer/Opacity"
+    );
+}
@@ -0,0 +1,59 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use enum_tree::{EnumTree, ToEnumTreeRoot, TryFromEnumTreeRoot};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum RootAction {
+    Menu(Box<Menu>),
+    Theme(Arc<Theme>),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum Menu {
+    Settings(Rc<Settings>),
+    Back,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Menu, RootAction)]
+pub enum Settings {
+    ToggleSound,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(RootAction, RootAction)]
+pub enum Theme {
+    Dark,
+}
+
+#[test]
+fn test_to_root_through_pointer_slots() {
+    let root = Settings::ToggleSound.to_root();
+    assert_eq!(
+        root,
+        RootAction::Menu(Box::new(Menu::Settings(Rc::new(Settings::ToggleSound))))
+    );
+    assert_eq!(
+        Theme::Dark.to_root(),
+        RootAction::Theme(Arc::new(Theme::Dark))
+    );
+}
+
+#[test]
+fn test_from_root_through_pointer_slots() {
+    let root = Settings::ToggleSound.to_root();
+    assert_eq!(
+        Settings::from_root(root.clone()),
+        Some(Settings::ToggleSound)
+    );
+    assert_eq!(Theme::from_root(root), None);
+
+    // A shared slot whose pointer is not unique is cloned out.
+    let theme = Arc::new(Theme::Dark);
+    let root = RootAction::Theme(theme.clone());
+    assert_eq!(Theme::from_root(root), Some(Theme::Dark));
+    assert_eq!(*theme, Theme::Dark);
+}
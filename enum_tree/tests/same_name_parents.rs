@@ -0,0 +1,65 @@
+use enum_tree::{EnumTree, ToEnumTreeRoot, TryFromEnumTreeRoot};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum RootOne {
+    Menu(a::Menu),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum RootTwo {
+    Menu(b::Menu),
+}
+
+/// Two distinct parents that share their name.
+mod a {
+    use super::*;
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree_inner(RootOne, RootOne)]
+    pub enum Menu {
+        Click(Click),
+    }
+}
+
+mod b {
+    use super::*;
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree_inner(RootTwo, RootTwo)]
+    pub enum Menu {
+        Click(Click),
+    }
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(a::Menu, RootOne)]
+#[enum_tree_leaf(b::Menu, RootTwo)]
+pub enum Click {
+    Left,
+}
+
+#[test]
+fn test_to_root_through_same_name_parents() {
+    let r1: RootOne = Click::Left.to_root();
+    let r2: RootTwo = Click::Left.to_root();
+    assert_eq!(r1, RootOne::Menu(a::Menu::Click(Click::Left)));
+    assert_eq!(r2, RootTwo::Menu(b::Menu::Click(Click::Left)));
+}
+
+#[test]
+fn test_from_root_through_same_name_parents() {
+    let r1 = RootOne::Menu(a::Menu::Click(Click::Left));
+    let r2 = RootTwo::Menu(b::Menu::Click(Click::Left));
+    assert_eq!(
+        <Click as TryFromEnumTreeRoot<RootOne>>::from_root(r1),
+        Some(Click::Left)
+    );
+    assert_eq!(
+        <Click as TryFromEnumTreeRoot<RootTwo>>::from_root(r2),
+        Some(Click::Left)
+    );
+    assert_eq!(a::Menu::from(Click::Left), a::Menu::Click(Click::Left));
+    assert_eq!(b::Menu::from(Click::Left), b::Menu::Click(Click::Left));
+}
@@ -0,0 +1,133 @@
+use enum_tree::{
+    EnumTree, EnumTreeNodePath, NodePath, ToEnumTreeRoot, ToEnumTreeRootVia, TryFromEnumTreeRoot,
+    TryFromEnumTreeRootAt, TryFromEnumTreeRootRef, TryFromEnumTreeRootVia,
+    TryFromEnumTreeRootWithContext,
+};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum AppAction {
+    File(File),
+    Network(Network),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+pub enum File {
+    Confirm(Confirm),
+    Open,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+pub enum Network {
+    Confirm(Confirm),
+    Disconnect,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(File, AppAction)]
+#[enum_tree_leaf(Network, AppAction)]
+pub enum Confirm {
+    Yes,
+    No,
+}
+
+#[test]
+fn test_to_root_via_each_parent() {
+    // `to_root` goes through the first declared parent.
+    assert_eq!(
+        Confirm::Yes.to_root(),
+        AppAction::File(File::Confirm(Confirm::Yes))
+    );
+    assert_eq!(
+        Confirm::Yes.to_root_via::<File>(),
+        AppAction::File(File::Confirm(Confirm::Yes))
+    );
+    assert_eq!(
+        Confirm::No.to_root_via::<Network>(),
+        AppAction::Network(Network::Confirm(Confirm::No))
+    );
+}
+
+fn path<P: EnumTreeNodePath<AppAction>>() -> &'static NodePath {
+    P::PATH
+}
+
+#[test]
+fn test_from_root_via_any_parent() {
+    let under_file = Confirm::Yes.to_root_via::<File>();
+    let under_network = Confirm::No.to_root_via::<Network>();
+
+    assert_eq!(
+        Confirm::from_root_via(under_file.clone()),
+        Some((Confirm::Yes, path::<File>()))
+    );
+    assert_eq!(
+        Confirm::from_root_via(under_network.clone()),
+        Some((Confirm::No, path::<crate::Network>()))
+    );
+    assert_eq!(
+        Confirm::from_root_via(AppAction::Network(Network::Disconnect)),
+        None
+    );
+
+    // A node with a single parent names it too.
+    assert_eq!(
+        File::from_root_via(under_file.clone()),
+        Some((File::Confirm(Confirm::Yes), path::<AppAction>()))
+    );
+    let (_, parent) = Confirm::from_root_via(under_network).unwrap();
+    assert_eq!(parent.to_string(), "Network");
+}
+
+#[test]
+fn test_from_root_under_any_parent() {
+    let under_file = Confirm::Yes.to_root_via::<File>();
+    let mut under_network = Confirm::No.to_root_via::<Network>();
+
+    assert_eq!(Confirm::from_root(under_file), Some(Confirm::Yes));
+    assert_eq!(Confirm::from_root_ref(&under_network), Some(&Confirm::No));
+    *Confirm::from_root_mut(&mut under_network).unwrap() = Confirm::Yes;
+    assert_eq!(Confirm::try_from(under_network), Ok(Confirm::Yes));
+
+    // Under neither parent, the mismatch is the one on the way through the first.
+    let mismatch = Confirm::try_from_root(AppAction::Network(Network::Disconnect)).unwrap_err();
+    assert_eq!((mismatch.expected(), mismatch.found()), ("File", "Network"));
+    assert_eq!(
+        mismatch.into_inner(),
+        AppAction::Network(Network::Disconnect)
+    );
+}
+
+#[test]
+fn test_from_root_at_under_any_parent() {
+    assert_eq!(
+        Confirm::from_root_at(Confirm::Yes.to_root_via::<File>()),
+        Some((Confirm::Yes, "Confirm"))
+    );
+    assert_eq!(
+        Confirm::from_root_at(Confirm::No.to_root_via::<Network>()),
+        Some((Confirm::No, "Confirm"))
+    );
+    assert_eq!(
+        Confirm::from_root_at(AppAction::Network(Network::Disconnect)),
+        None
+    );
+}
+
+#[test]
+fn test_from_root_with_context_under_any_parent() {
+    assert_eq!(
+        Confirm::from_root_with_context(Confirm::No.to_root_via::<Network>()),
+        Some((Confirm::No, (((), ()), ())))
+    );
+    assert_eq!(
+        Confirm::from_root_with_context(Confirm::Yes.to_root_via::<File>()),
+        Some((Confirm::Yes, (((), ()), ())))
+    );
+    assert_eq!(
+        Confirm::from_root_with_context(AppAction::File(File::Open)),
+        None
+    );
+}
@@ -0,0 +1,68 @@
+use enum_tree::{EnumTree, EnumTreeLeaf, ToEnumTreeRoot, TryFromEnumTreeRoot};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum RootAction {
+    Audio(Audio),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum Audio {
+    SetVolume(SetVolume),
+    Mute(Mute),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Audio, RootAction)]
+pub struct SetVolume {
+    level: u8,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Audio, RootAction)]
+pub struct Mute(bool);
+
+/// A separate, top-down tree whose leaf is a struct.
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root(top_down)]
+pub enum Video {
+    Brightness(Brightness),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+pub struct Brightness(u8);
+
+fn assert_leaf<T: EnumTreeLeaf<R>, R>() {}
+
+#[test]
+fn test_struct_leaves_are_leaves() {
+    assert_leaf::<SetVolume, RootAction>();
+    assert_leaf::<Mute, RootAction>();
+    assert_leaf::<Brightness, Video>();
+}
+
+#[test]
+fn test_struct_leaf_round_trip() {
+    let root = SetVolume { level: 3 }.to_root();
+    assert_eq!(
+        root,
+        RootAction::Audio(Audio::SetVolume(SetVolume { level: 3 }))
+    );
+    assert_eq!(
+        SetVolume::from_root(root.clone()),
+        Some(SetVolume { level: 3 })
+    );
+    assert_eq!(Mute::from_root(root), None);
+
+    let mute = Mute(true);
+    assert_eq!(Audio::from(mute.clone()), Audio::Mute(Mute(true)));
+    assert_eq!(Mute::try_from(Audio::Mute(Mute(true))), Ok(mute));
+}
+
+#[test]
+fn test_top_down_struct_leaf_round_trip() {
+    let root: Video = Brightness(7).to_root();
+    assert_eq!(root, Video::Brightness(Brightness(7)));
+    assert_eq!(Brightness::from_root(root), Some(Brightness(7)));
+}
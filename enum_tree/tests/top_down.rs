@@ -0,0 +1,165 @@
+use enum_tree::{EnumTree, EnumTreeInner, EnumTreeLeaf, ToEnumTreeRoot, TryFromEnumTreeRoot};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root(top_down)]
+pub enum AppAction {
+    Menu(Menu),
+    Audio(Audio),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+pub enum Menu {
+    Settings(Settings),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+pub enum Settings {
+    ToggleSound,
+    Volume { level: u8 },
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+pub enum Audio {
+    Mute,
+}
+
+fn assert_inner<T: EnumTreeInner<R>, R>() {}
+fn assert_leaf<T: EnumTreeLeaf<R>, R>() {}
+
+#[test]
+fn test_top_down_markers() {
+    assert_inner::<Menu, AppAction>();
+    assert_leaf::<Settings, AppAction>();
+    assert_leaf::<Audio, AppAction>();
+}
+
+#[test]
+fn test_top_down_round_trip() {
+    let root = Settings::Volume { level: 3 }.to_root();
+    assert_eq!(
+        root,
+        AppAction::Menu(Menu::Settings(Settings::Volume { level: 3 }))
+    );
+    assert_eq!(
+        Settings::from_root(root.clone()),
+        Some(Settings::Volume { level: 3 })
+    );
+    assert_eq!(Audio::from_root(root), None);
+
+    let root = Audio::Mute.to_root();
+    assert_eq!(root, AppAction::Audio(Audio::Mute));
+}
+
+mod multiple_roots {
+    use super::*;
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree_root(top_down)]
+    pub enum RootOne {
+        Parent(Parent),
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree_root(top_down)]
+    pub enum RootTwo {
+        Parent(Parent),
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    pub enum Parent {
+        Leaf(Leaf),
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    pub enum Leaf {
+        A,
+    }
+
+    #[test]
+    fn test_top_down_multiple_roots() {
+        let r1: RootOne = Leaf::A.to_root();
+        let r2: RootTwo = Leaf::A.to_root();
+        assert_eq!(r1, RootOne::Parent(Parent::Leaf(Leaf::A)));
+        assert_eq!(r2, RootTwo::Parent(Parent::Leaf(Leaf::A)));
+        assert_eq!(
+            <Leaf as TryFromEnumTreeRoot<RootOne>>::from_root(r1),
+            Some(Leaf::A)
+        );
+        assert_eq!(
+            <Leaf as TryFromEnumTreeRoot<RootTwo>>::from_root(r2),
+            Some(Leaf::A)
+        );
+    }
+}
+
+mod mounted_root {
+    use super::*;
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree_root]
+    pub enum Outer {
+        Plugin(PluginAction),
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree_root(top_down)]
+    #[enum_tree_inner(Outer, Outer, variant = Plugin)]
+    pub enum PluginAction {
+        Tool(Tool),
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    pub enum Tool {
+        Brush(Brush),
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    pub enum Brush {
+        Resize { size: u8 },
+    }
+
+    #[test]
+    fn test_top_down_descendants_reach_outer_root() {
+        let brush = Brush::Resize { size: 2 };
+        let plugin: PluginAction = brush.clone().to_root();
+        let outer: Outer = brush.clone().to_root();
+        assert_eq!(plugin, PluginAction::Tool(Tool::Brush(brush.clone())));
+        assert_eq!(outer, Outer::Plugin(plugin));
+        assert_eq!(
+            <Brush as TryFromEnumTreeRoot<Outer>>::from_root(outer),
+            Some(brush)
+        );
+    }
+}
+
+mod tuple_payloads {
+    use super::*;
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree_root(top_down)]
+    pub enum App {
+        IpSetup(IpSetup),
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree(allow_tuple)]
+    pub enum IpSetup {
+        UpdatePortText(String),
+        Scroll(f32, f32),
+        ClickStartIp,
+    }
+
+    #[test]
+    fn test_top_down_tuple_payloads() {
+        assert_leaf::<IpSetup, App>();
+        for leaf in [
+            IpSetup::UpdatePortText("8080".into()),
+            IpSetup::Scroll(1.0, 2.0),
+            IpSetup::ClickStartIp,
+        ] {
+            let root = leaf.clone().to_root();
+            assert_eq!(root, App::IpSetup(leaf.clone()));
+            assert_eq!(IpSetup::from_root(root), Some(leaf));
+        }
+    }
+}
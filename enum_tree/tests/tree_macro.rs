@@ -0,0 +1,76 @@
+use enum_tree::{
+    EnumTreeInner, EnumTreeLeaf, EnumTreeNodes, EnumTreeRoot, ToEnumTreeRoot, TryFromEnumTreeRoot,
+};
+
+enum_tree::enum_tree! {
+    /// Every action the application can dispatch.
+    #[derive(Clone, Debug, PartialEq)]
+    pub AppAction {
+        Menu {
+            Settings { ToggleSound, Volume { level: u8 } },
+            Quit { Now, Later },
+            Back,
+        },
+        /// Network actions.
+        #[enum_tree(allow_tuple)]
+        Network { Connect { host: String, port: u16 }, Disconnect, Ping(u32) },
+    }
+}
+
+fn assert_root<T: EnumTreeRoot<R>, R>() {}
+fn assert_inner<T: EnumTreeInner<R>, R>() {}
+fn assert_leaf<T: EnumTreeLeaf<R>, R>() {}
+
+#[test]
+fn test_tree_macro_node_kinds() {
+    assert_root::<AppAction, AppAction>();
+    assert_inner::<Menu, AppAction>();
+    assert_leaf::<Settings, AppAction>();
+    assert_leaf::<Quit, AppAction>();
+    assert_leaf::<Network, AppAction>();
+}
+
+#[test]
+fn test_tree_macro_round_trip() {
+    let root = Settings::Volume { level: 4 }.to_root();
+    assert_eq!(
+        root,
+        AppAction::Menu(Menu::Settings(Settings::Volume { level: 4 }))
+    );
+    assert_eq!(
+        Settings::from_root(root.clone()),
+        Some(Settings::Volume { level: 4 })
+    );
+    assert_eq!(Network::from_root(root), None);
+
+    let connect = Network::Connect {
+        host: "localhost".into(),
+        port: 80,
+    };
+    assert_eq!(Network::from_root(connect.clone().to_root()), Some(connect));
+}
+
+#[test]
+fn test_tree_macro_tuple_variants_are_leaf_payloads() {
+    assert_leaf::<Network, AppAction>();
+    let root = Network::Ping(7).to_root();
+    assert_eq!(root, AppAction::Network(Network::Ping(7)));
+    assert_eq!(Network::from_root(root), Some(Network::Ping(7)));
+}
+
+#[test]
+fn test_tree_macro_inner_node_with_leaf_variants() {
+    assert_inner::<Menu, AppAction>();
+    let root = Menu::Back.to_root();
+    assert_eq!(root, AppAction::Menu(Menu::Back));
+    assert_eq!(Menu::from_root(root), Some(Menu::Back));
+}
+
+#[test]
+fn test_tree_macro_lists_nodes() {
+    let nodes: Vec<String> = AppAction::NODES
+        .iter()
+        .map(|path| path.to_string())
+        .collect();
+    assert_eq!(nodes, ["", "Menu", "Menu/Settings", "Menu/Quit", "Network"]);
+}
@@ -0,0 +1,137 @@
+use std::rc::Rc;
+
+use enum_tree::{EnumTree, EnumTreeNodePath, EnumTreePath, NodePath, ToEnumTreeRoot};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum AppAction {
+    Menu(Box<Menu>),
+    Editor(Editor),
+    Quit,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+pub enum Menu {
+    Settings(Rc<Settings>),
+    Confirm(Confirm),
+    Close,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Menu, AppAction)]
+pub enum Settings {
+    ToggleSound,
+    Volume { level: u8 },
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction)]
+pub enum Editor {
+    Buffer {
+        id: u32,
+        #[enum_tree(child)]
+        action: BufferAction,
+    },
+    Prompt(Confirm),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Editor, AppAction, variant = Buffer)]
+pub enum BufferAction {
+    Save,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(Menu, AppAction)]
+#[enum_tree_leaf(Editor, AppAction, variant = Prompt)]
+pub struct Confirm {
+    yes: bool,
+}
+
+fn path<T: EnumTreeNodePath<AppAction>>() -> &'static NodePath {
+    T::PATH
+}
+
+#[test]
+fn test_tree_path_walks_down_to_the_leaf_variant() {
+    let root = Settings::ToggleSound.to_root();
+    assert!(root.tree_path().eq(["Menu", "Settings", "ToggleSound"]));
+    assert_eq!(root.tree_path().to_string(), "Menu/Settings/ToggleSound");
+    assert_eq!(
+        format!("{:?}", Settings::Volume { level: 1 }.tree_path()),
+        r#"["Volume"]"#
+    );
+    assert!(AppAction::Quit.tree_path().eq(["Quit"]));
+    assert!(Menu::Close.tree_path().eq(["Close"]));
+
+    let root = AppAction::Editor(Editor::Buffer {
+        id: 1,
+        action: BufferAction::Save,
+    });
+    assert_eq!(root.tree_path().to_string(), "Editor/Buffer/Save");
+}
+
+#[test]
+fn test_struct_leaves_end_at_their_parent_variant() {
+    let root = Confirm { yes: true }.to_root();
+    assert_eq!(root.tree_path().to_string(), "Menu/Confirm");
+    let editor = Editor::Prompt(Confirm { yes: false });
+    assert_eq!(editor.tree_path().to_string(), "Prompt");
+    assert_eq!(Confirm { yes: true }.tree_path().count(), 0);
+}
+
+#[test]
+fn test_node_path_from_the_root() {
+    assert!(path::<AppAction>().is_empty());
+    assert_eq!(path::<AppAction>().to_string(), "");
+    assert_eq!(path::<Menu>().to_string(), "Menu");
+    assert!(path::<Settings>().iter().eq(["Menu", "Settings"]));
+    assert_eq!(path::<Settings>().len(), 2);
+    assert_eq!(path::<BufferAction>().to_string(), "Editor/Buffer");
+    assert_eq!(path::<BufferAction>().variant(), Some("Buffer"));
+    // Through the first parent only.
+    assert_eq!(path::<Confirm>().to_string(), "Menu/Confirm");
+    assert_eq!(format!("{:?}", path::<Confirm>()), r#"["Menu", "Confirm"]"#);
+}
+
+#[test]
+fn test_node_path_joins_the_value_path() {
+    let settings = Settings::Volume { level: 2 };
+    let full: Vec<&str> = path::<Settings>()
+        .iter()
+        .chain(settings.tree_path())
+        .collect();
+    assert_eq!(full, ["Menu", "Settings", "Volume"]);
+    assert!(settings.to_root().tree_path().eq(full));
+}
+
+mod top_down {
+    use enum_tree::{EnumTree, EnumTreeNodePath, EnumTreePath, ToEnumTreeRoot};
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    #[enum_tree_root(top_down)]
+    pub enum Root {
+        Tools(Tools),
+    }
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    pub enum Tools {
+        Brush(Brush),
+    }
+
+    #[derive(EnumTree, Debug, PartialEq)]
+    pub enum Brush {
+        Size { px: u8 },
+    }
+
+    #[test]
+    fn test_top_down_paths() {
+        let root = Brush::Size { px: 3 }.to_root();
+        assert_eq!(root.tree_path().to_string(), "Tools/Brush/Size");
+        assert_eq!(
+            <Brush as EnumTreeNodePath<Root>>::PATH.to_string(),
+            "Tools/Brush"
+        );
+    }
+}
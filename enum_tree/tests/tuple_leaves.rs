@@ -0,0 +1,41 @@
+use enum_tree::{EnumTree, EnumTreeLeaf, ToEnumTreeRoot, TryFromEnumTreeRoot};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum RootAction {
+    MenuFlow(MenuFlow),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum MenuFlow {
+    IpSetup(IpSetup),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(MenuFlow, RootAction, allow_tuple)]
+pub enum IpSetup {
+    UpdatePortText(String),
+    Scroll(f32, f32),
+    ClickStartIp,
+}
+
+fn assert_leaf<T: EnumTreeLeaf<R>, R>() {}
+
+#[test]
+fn test_tuple_leaf_is_a_leaf() {
+    assert_leaf::<IpSetup, RootAction>();
+}
+
+#[test]
+fn test_tuple_leaf_round_trip() {
+    let root = IpSetup::Scroll(1.0, 2.0).to_root();
+    assert_eq!(
+        root,
+        RootAction::MenuFlow(MenuFlow::IpSetup(IpSetup::Scroll(1.0, 2.0)))
+    );
+    assert_eq!(IpSetup::from_root(root), Some(IpSetup::Scroll(1.0, 2.0)));
+
+    let text = IpSetup::UpdatePortText("8080".into());
+    assert_eq!(IpSetup::from_root(text.clone().to_root()), Some(text));
+}
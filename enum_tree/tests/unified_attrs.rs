@@ -0,0 +1,82 @@
+use enum_tree::{
+    EnumTree, EnumTreeInner, EnumTreeLeaf, EnumTreeNodePath, ToEnumTreeRoot, ToEnumTreeRootVia,
+    TryFromEnumTreeRoot, TryFromEnumTreeRootVia,
+};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree(kind = root)]
+pub enum AppAction {
+    Menu(Menu),
+    Network(Network),
+    Dialog(Dialog),
+}
+
+// The root is inferred from the parent, which belongs to a single tree.
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree(kind = inner, parent = AppAction)]
+pub enum Menu {
+    Settings(Settings),
+    Confirm(Confirm),
+    Back,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree(kind = leaf, parent = Menu)]
+pub enum Settings {
+    ToggleSound,
+}
+
+// The unified attribute and the positional ones can be mixed in one tree.
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(AppAction, AppAction)]
+pub enum Network {
+    Disconnect,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree(kind = inner, parent = AppAction)]
+pub enum Dialog {
+    Confirm(Confirm),
+}
+
+// Both parents are in the same tree, which the root is inferred from once.
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree(kind = leaf, parent = Menu)]
+#[enum_tree(kind = leaf, parent = Dialog)]
+pub enum Confirm {
+    Yes,
+}
+
+fn assert_inner<T: EnumTreeInner<R>, R>() {}
+fn assert_leaf<T: EnumTreeLeaf<R>, R>() {}
+
+#[test]
+fn test_inferred_root() {
+    assert_inner::<Menu, AppAction>();
+    assert_leaf::<Settings, AppAction>();
+
+    let root = Settings::ToggleSound.to_root();
+    assert_eq!(root, AppAction::Menu(Menu::Settings(Settings::ToggleSound)));
+    assert_eq!(Settings::from_root(root), Some(Settings::ToggleSound));
+    assert_eq!(
+        Network::from_root(Network::Disconnect.to_root()),
+        Some(Network::Disconnect)
+    );
+}
+
+#[test]
+fn test_inferred_root_from_several_parents() {
+    assert_leaf::<Confirm, AppAction>();
+
+    let under_menu = Confirm::Yes.to_root();
+    assert_eq!(under_menu, AppAction::Menu(Menu::Confirm(Confirm::Yes)));
+    let under_dialog = Confirm::Yes.to_root_via::<Dialog>();
+    assert_eq!(
+        under_dialog,
+        AppAction::Dialog(Dialog::Confirm(Confirm::Yes))
+    );
+    assert_eq!(
+        Confirm::from_root_via(under_dialog),
+        Some((Confirm::Yes, <Dialog as EnumTreeNodePath<AppAction>>::PATH))
+    );
+}
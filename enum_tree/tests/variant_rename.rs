@@ -0,0 +1,51 @@
+use enum_tree::{EnumTree, ToEnumTreeRoot, TryFromEnumTreeRoot};
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_root]
+pub enum AppAction {
+    Menu(MenuAction),
+    Network(NetworkAction),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_inner(AppAction, AppAction, variant = Menu)]
+pub enum MenuAction {
+    Settings(SettingsAction),
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(MenuAction, AppAction, variant = Settings)]
+pub enum SettingsAction {
+    ToggleSound,
+}
+
+#[derive(EnumTree, Clone, Debug, PartialEq)]
+#[enum_tree_leaf(AppAction, AppAction, variant = Network)]
+pub enum NetworkAction {
+    Connect { port: u16 },
+}
+
+#[test]
+fn test_to_root_renamed_variants() {
+    let root = SettingsAction::ToggleSound.to_root();
+    assert_eq!(
+        root,
+        AppAction::Menu(MenuAction::Settings(SettingsAction::ToggleSound))
+    );
+
+    let root = NetworkAction::Connect { port: 80 }.to_root();
+    assert_eq!(
+        root,
+        AppAction::Network(NetworkAction::Connect { port: 80 })
+    );
+}
+
+#[test]
+fn test_from_root_renamed_variants() {
+    let root = AppAction::Menu(MenuAction::Settings(SettingsAction::ToggleSound));
+    assert_eq!(
+        SettingsAction::from_root(root.clone()),
+        Some(SettingsAction::ToggleSound)
+    );
+    assert_eq!(NetworkAction::from_root(root), None);
+}
@@ -0,0 +1,442 @@
+//! Parsing and validation of the `#[enum_tree_*]` helper attributes.
+
+use syn::{
+    Attribute, DeriveInput, Fields, Ident, Token, Type,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use crate::Errors;
+
+/// Arguments of a `#[enum_tree_inner(P, R)]` or `#[enum_tree_leaf(P, R)]` attribute.
+///
+/// Optional trailing arguments:
+/// - `variant = V` names the parent variant wrapping this node when it differs from the node's
+///   own type name.
+/// - `allow_tuple` (leaves only) accepts tuple variants, which are then leaf payloads.
+///
+/// A root written `_` is inferred from the parent, as `#[enum_tree(parent = P, ..)]` without
+/// `root` does; [`parent_attrs`] replaces it with the parent's `SingleRoot::Root`.
+pub(crate) struct ParentAttr {
+    pub(crate) parent: Type,
+    pub(crate) root: Type,
+    pub(crate) root_inferred: bool,
+    pub(crate) variant: Option<Ident>,
+    pub(crate) allow_tuple: Option<Ident>,
+}
+
+impl Parse for ParentAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Err(input.error("expected parent and root types, e.g. `(Parent, Root)`"));
+        }
+        let parent: Type = input.parse()?;
+        let missing_root = "expected root type after the parent type, e.g. `(Parent, Root)`";
+        if input.is_empty() {
+            return Err(input.error(missing_root));
+        }
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            return Err(input.error(missing_root));
+        }
+        let root: Type = input.parse()?;
+
+        let mut variant = None;
+        let mut allow_tuple = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key == "variant" && variant.is_none() {
+                input.parse::<Token![=]>()?;
+                variant = Some(input.parse()?);
+            } else if key == "allow_tuple" && allow_tuple.is_none() {
+                allow_tuple = Some(key);
+            } else if key == "variant" || key == "allow_tuple" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("duplicate argument `{key}`"),
+                ));
+            } else {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "unknown argument `{key}`, expected `variant = VariantName` or `allow_tuple`"
+                    ),
+                ));
+            }
+        }
+        if !input.is_empty() {
+            return Err(input.error(
+                "unexpected extra argument, expected `(Parent, Root)` followed by `variant = VariantName` or `allow_tuple`",
+            ));
+        }
+
+        Ok(ParentAttr {
+            parent,
+            root_inferred: matches!(root, Type::Infer(_)),
+            root,
+            variant,
+            allow_tuple,
+        })
+    }
+}
+
+/// Parses every `#[<name>(P, R)]` attribute of `input`, recording malformed ones in `errors`.
+///
+/// Every inferred root is taken from the first parent inferring one, so that parents in the same
+/// tree share one set of impls; the others are checked to be in that tree.
+pub(crate) fn parent_attrs(
+    input: &DeriveInput,
+    name: &str,
+    errors: &mut Errors,
+) -> Vec<ParentAttr> {
+    let mut parents = Vec::new();
+    for attr in input.attrs.iter() {
+        if attr.path().is_ident(name) {
+            // Expect attribute like #[enum_tree_inner(P, R)] or #[enum_tree_leaf(P, R, variant = V)]
+            match attr.parse_args::<ParentAttr>() {
+                Ok(parent) => {
+                    if let Some(key) = &parent.allow_tuple
+                        && name != "enum_tree_leaf"
+                    {
+                        errors.push(syn::Error::new(
+                            key.span(),
+                            "`allow_tuple` only applies to `#[enum_tree_leaf]`",
+                        ));
+                    }
+                    parents.push(parent);
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+    }
+    let krate = crate_path(input);
+    if let Some(first) = parents.iter().find(|parent| parent.root_inferred) {
+        let first = first.parent.clone();
+        for parent in parents.iter_mut().filter(|parent| parent.root_inferred) {
+            parent.root = syn::parse_quote_spanned! {parent.root.span()=>
+                <#first as #krate::__private::SingleRoot>::Root
+            };
+        }
+    }
+    parents
+}
+
+/// Whether any `#[enum_tree_inner]` or `#[enum_tree_leaf]` attribute of the node fails to parse,
+/// so that the parents it meant to declare are unknown.
+pub(crate) fn has_malformed_parent_attr(input: &DeriveInput) -> bool {
+    input.attrs.iter().any(|attr| {
+        (attr.path().is_ident("enum_tree_inner") || attr.path().is_ident("enum_tree_leaf"))
+            && attr.parse_args::<ParentAttr>().is_err()
+    })
+}
+
+/// Finds the field of a parent variant marked `#[enum_tree(child)]`, recording malformed markers
+/// and extra marked fields in `errors`.
+pub(crate) fn marked_child_field(fields: &Fields, errors: &mut Errors) -> Option<usize> {
+    let mut marked = None;
+    for (index, field) in fields.iter().enumerate() {
+        for attr in field.attrs.iter() {
+            if !attr.path().is_ident("enum_tree") {
+                continue;
+            }
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("child") {
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown argument, expected `child`"))
+                }
+            });
+            match parsed {
+                Ok(()) if marked.is_none() => marked = Some(index),
+                Ok(()) => errors.push(syn::Error::new_spanned(
+                    attr,
+                    "only one field of a variant can be marked `#[enum_tree(child)]`",
+                )),
+                Err(err) => errors.push(err),
+            }
+        }
+    }
+    marked
+}
+
+/// Options of the `#[enum_tree_root]` attribute.
+#[derive(Default)]
+pub(crate) struct RootAttr {
+    pub(crate) top_down: bool,
+}
+
+/// Parses the `#[enum_tree_root]` / `#[enum_tree_root(top_down)]` attributes of `input`.
+pub(crate) fn root_attr(input: &DeriveInput, errors: &mut Errors) -> RootAttr {
+    let mut root = RootAttr::default();
+    for attr in input.attrs.iter() {
+        if attr.path().is_ident("enum_tree_root") && !matches!(attr.meta, syn::Meta::Path(_)) {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("top_down") {
+                    root.top_down = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown argument, expected `top_down`"))
+                }
+            });
+            if let Err(err) = parsed {
+                errors.push(err);
+            }
+        }
+    }
+    root
+}
+
+/// Rewrites every `#[enum_tree(kind = .., ..)]` attribute of `input` into the equivalent
+/// `#[enum_tree_root]`, `#[enum_tree_inner(P, R)]` or `#[enum_tree_leaf(P, R)]`, recording
+/// malformed ones in `errors`.
+///
+/// Arguments: `kind = root | inner | leaf`, `parent = P` (inner and leaf), `root = R`,
+/// `variant = V`, `allow_tuple` and `top_down` (root). Without `root`, the root is `_`: the single
+/// root of the parent.
+///
+/// `crate = path` may be given in any of them, or alone; it is kept as a single
+/// `#[enum_tree(crate = path)]` for [`crate_path`]. `allow_tuple` alone is kept as
+/// `#[enum_tree(allow_tuple)]` for [`allow_tuple_attr`].
+pub(crate) fn desugar_enum_tree_attrs(input: &mut DeriveInput, errors: &mut Errors) {
+    let mut crate_attr: Option<Attribute> = None;
+    for attr in std::mem::take(&mut input.attrs) {
+        if !attr.path().is_ident("enum_tree") {
+            input.attrs.push(attr);
+            continue;
+        }
+        match desugar_enum_tree_attr(&attr) {
+            Ok((path, desugared)) => {
+                if let Some(path) = path {
+                    if crate_attr.is_some() {
+                        errors.push(syn::Error::new_spanned(path, "duplicate argument `crate`"));
+                    } else {
+                        crate_attr = Some(
+                            syn::parse_quote_spanned!(attr.span()=> #[enum_tree(crate = #path)]),
+                        );
+                    }
+                }
+                input.attrs.extend(desugared);
+            }
+            Err(err) => errors.push(err),
+        }
+    }
+    input.attrs.extend(crate_attr);
+}
+
+/// The `allow_tuple` key of a `#[enum_tree(allow_tuple)]` attribute without `kind`, which lets a
+/// leaf, declared top-down, by its attributes or in `enum_tree!`, hold tuple variants as payloads.
+pub(crate) fn allow_tuple_attr(input: &DeriveInput) -> Option<Ident> {
+    let mut key = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("enum_tree"))
+    {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("allow_tuple") {
+                key = meta.path.get_ident().cloned();
+            } else if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<Type>()?;
+            }
+            Ok(())
+        });
+    }
+    key
+}
+
+/// The path of the `enum_tree` crate in generated code: `::enum_tree`, unless overridden with
+/// `#[enum_tree(crate = path)]`. Malformed attributes are reported by `desugar_enum_tree_attrs`.
+pub(crate) fn crate_path(input: &DeriveInput) -> proc_macro2::TokenStream {
+    for attr in input.attrs.iter() {
+        if !attr.path().is_ident("enum_tree") {
+            continue;
+        }
+        let mut krate: Option<syn::Path> = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = Some(meta.value()?.parse()?);
+            } else if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<Type>()?;
+            }
+            Ok(())
+        });
+        if let Some(path) = krate {
+            return quote::quote!(#path);
+        }
+    }
+    quote::quote!(::enum_tree)
+}
+
+fn desugar_enum_tree_attr(attr: &Attribute) -> syn::Result<(Option<syn::Path>, Option<Attribute>)> {
+    let mut path: Option<syn::Path> = None;
+    let mut kind: Option<Ident> = None;
+    let mut parent: Option<Type> = None;
+    let mut root: Option<Type> = None;
+    let mut variant: Option<Ident> = None;
+    let mut allow_tuple: Option<Ident> = None;
+    let mut top_down: Option<Ident> = None;
+    attr.parse_nested_meta(|meta| {
+        let Some(key) = meta.path.get_ident().cloned() else {
+            return Err(meta.error(
+                "expected `kind`, `parent`, `root`, `variant`, `allow_tuple`, `top_down` or `crate`",
+            ));
+        };
+        let duplicate = || syn::Error::new(key.span(), format!("duplicate argument `{key}`"));
+        if key == "crate" {
+            if path.is_some() {
+                return Err(duplicate());
+            }
+            path = Some(meta.value()?.parse()?);
+        } else if key == "kind" {
+            if kind.is_some() {
+                return Err(duplicate());
+            }
+            let value: Ident = meta.value()?.parse()?;
+            if value != "root" && value != "inner" && value != "leaf" {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "expected `kind = root`, `kind = inner` or `kind = leaf`",
+                ));
+            }
+            kind = Some(value);
+        } else if key == "parent" || key == "root" {
+            let slot = if key == "parent" { &mut parent } else { &mut root };
+            if slot.is_some() {
+                return Err(duplicate());
+            }
+            *slot = Some(meta.value()?.parse()?);
+        } else if key == "variant" {
+            if variant.is_some() {
+                return Err(duplicate());
+            }
+            variant = Some(meta.value()?.parse()?);
+        } else if key == "allow_tuple" || key == "top_down" {
+            let slot = if key == "allow_tuple" { &mut allow_tuple } else { &mut top_down };
+            if slot.is_some() {
+                return Err(duplicate());
+            }
+            *slot = Some(key);
+        } else {
+            return Err(syn::Error::new(
+                key.span(),
+                format!(
+                    "unknown argument `{key}`, expected `kind`, `parent`, `root`, `variant`, `allow_tuple`, `top_down` or `crate`"
+                ),
+            ));
+        }
+        Ok(())
+    })?;
+
+    let span = attr.span();
+    // Without `kind`, the attribute only sets the crate path or marks a leaf's tuple variants as
+    // payloads.
+    let no_kind_args = (path.is_some() || allow_tuple.is_some())
+        && parent.is_none()
+        && root.is_none()
+        && variant.is_none()
+        && top_down.is_none();
+    let Some(kind) = kind else {
+        if no_kind_args {
+            return Ok((
+                path,
+                allow_tuple.map(|key| syn::parse_quote_spanned!(span=> #[enum_tree(#key)])),
+            ));
+        }
+        return Err(syn::Error::new(
+            span,
+            "missing `kind = root`, `kind = inner` or `kind = leaf`",
+        ));
+    };
+
+    if kind == "root" {
+        if let Some(ty) = &parent {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "`parent` does not apply to `kind = root`; mount a root in another tree with a separate `kind = inner` attribute",
+            ));
+        }
+        if let Some(ty) = &root {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "`root` does not apply to `kind = root`",
+            ));
+        }
+        if let Some(key) = variant.as_ref().or(allow_tuple.as_ref()) {
+            let name = if variant.is_some() {
+                "variant"
+            } else {
+                "allow_tuple"
+            };
+            return Err(syn::Error::new(
+                key.span(),
+                format!("`{name}` does not apply to `kind = root`"),
+            ));
+        }
+        return Ok((
+            path,
+            Some(match top_down {
+                Some(top_down) => syn::parse_quote_spanned!(span=> #[enum_tree_root(#top_down)]),
+                None => syn::parse_quote_spanned!(span=> #[enum_tree_root]),
+            }),
+        ));
+    }
+
+    if let Some(key) = top_down {
+        return Err(syn::Error::new(
+            key.span(),
+            "`top_down` only applies to `kind = root`",
+        ));
+    }
+    let Some(parent) = parent else {
+        return Err(syn::Error::new(
+            span,
+            format!("missing `parent = ...` for `kind = {kind}`"),
+        ));
+    };
+    let root = root.unwrap_or_else(|| syn::parse_quote_spanned!(span=> _));
+    let variant = variant.map(|v| quote::quote!(, variant = #v));
+    let allow_tuple = allow_tuple.map(|key| quote::quote!(, #key));
+    let desugared = if kind == "inner" {
+        syn::parse_quote_spanned!(span=> #[enum_tree_inner(#parent, #root #variant #allow_tuple)])
+    } else {
+        syn::parse_quote_spanned!(span=> #[enum_tree_leaf(#parent, #root #variant #allow_tuple)])
+    };
+    Ok((path, Some(desugared)))
+}
+
+/// Reports attribute combinations that have no meaning together.
+///
+/// `#[enum_tree_root]` can't be repeated, and can only be combined with `#[enum_tree_inner]`;
+/// `#[enum_tree_leaf]` can't be combined with `#[enum_tree_root]` or `#[enum_tree_inner]`.
+pub(crate) fn check_attr_combination(input: &DeriveInput, errors: &mut Errors) {
+    let of = |name: &str| -> Vec<&Attribute> {
+        input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(name))
+            .collect()
+    };
+    let roots = of("enum_tree_root");
+    let inners = of("enum_tree_inner");
+    let leaves = of("enum_tree_leaf");
+
+    for attr in roots.iter().skip(1) {
+        errors.push(syn::Error::new_spanned(
+            attr,
+            "duplicate `#[enum_tree_root]` attribute",
+        ));
+    }
+    if !roots.is_empty() || !inners.is_empty() {
+        let other = if roots.is_empty() {
+            "#[enum_tree_inner]"
+        } else {
+            "#[enum_tree_root]"
+        };
+        for attr in leaves {
+            errors.push(syn::Error::new_spanned(
+                attr,
+                format!("`#[enum_tree_leaf]` cannot be combined with `{other}`"),
+            ));
+        }
+    }
+}
@@ -1,39 +1,192 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{Attribute, Data, DataEnum, DeriveInput, Fields, Type, spanned::Spanned};
+use quote::{quote, quote_spanned};
+use syn::{Data, DataEnum, DeriveInput, Fields, Ident, Type, spanned::Spanned};
 
+use attrs::ParentAttr;
+
+mod attrs;
 #[cfg(test)]
 mod tests;
+mod tree_macro;
 
-/// `#[derive(EnumTree)]` only works on enums.
+/// `#[derive(EnumTree)]` works on enums, and on structs and tuple structs used as leaves.
+///
+/// The derive will implement the `EnumTree` trait, and depending on the attribute, one of the
+/// `EnumTreeRoot`, `EnumTreeInner`, or `EnumTreeLeaf` marker traits.
+///
+/// # Attributes
 ///
 /// Needs one of the following attributes:
 /// - `#[enum_tree_root]`
 /// - `#[enum_tree_inner(P,R)]`, where P is the parent enum type, and R is the root enum type
 /// - `#[enum_tree_leaf(P,R)]`, where P is the parent enum type, and R is the root enum type
 ///
-/// The derive will implement the `EnumTree` trait, and depending on the attribute, one of the
-/// `EnumTreeRoot`, `EnumTreeInner`, or `EnumTreeLeaf` marker traits.
+/// unless the enum's place in the tree is declared top-down by its parent (see below).
+///
+/// `#[enum_tree(kind = root | inner | leaf, parent = P, root = R)]` is an equivalent single
+/// attribute, taking the other options (`variant = V`, `allow_tuple`, `top_down`) by name too.
+/// `root` may be left out when the parent is the root or an inner node of a single tree: it is
+/// then that tree's root, as with `_` in the positional attributes. A node with several parents
+/// infers it from the first one; the others must be in the same tree.
+///
+/// Generated code names the runtime crate `::enum_tree`; when it is re-exported from another
+/// crate, point the derive at it with `#[enum_tree(crate = path::to::enum_tree)]`, on its own or
+/// next to `kind`. Standard library items are always named through `::core`, so local items
+/// named `Option` or `Result` don't interfere.
+///
+/// `#[enum_tree_root]` may be combined with one or more `#[enum_tree_inner(P,R)]` attributes to
+/// mount the root of one tree as an inner node of other trees; both sets of impls are generated.
+/// Nodes that belong to a single tree join every tree its root is mounted in without listing
+/// them, with the same parents, but get `TryFrom` only from the root they declare.
+///
+/// Inner nodes and the root node have variants that wrap their child enum types;
+/// i.e., single tuple variants with one slot, whose type is one of the child enum types.
+/// They may also have unit and struct variants, which are leaves attached directly to the node:
+/// `from_root` for the node yields them like any other of its variants.
+/// The child may be held as `Box<Child>`, `Rc<Child>` or `Arc<Child>`.
+/// A variant may also carry context next to its child, e.g.
+/// `Buffer { id: BufferId, #[enum_tree(child)] action: BufferAction }` or
+/// `Split(u8, #[enum_tree(child)] Pane)`.
+/// Variant names MUST match the name of the child enum type, unless the child names the
+/// wrapping variant explicitly with `variant = V`, e.g. `#[enum_tree_leaf(P, R, variant = V)]`.
+/// A parent may hold the same child type in several variants, e.g. `Primary(Button)` and
+/// `Secondary(Button)`; the child names one of them with `variant = V`. The named variant only
+/// sets the child's `EnumTreeNodePath::PATH`; the conversions cover every variant, so naming the
+/// first one is the usual choice.
+/// A node may also declare several parents in the same tree, with one attribute each. The first
+/// is its `EnumTree::P`, used by `to_root`.
+///
+/// Leaf nodes must have either unit variants, or struct variants (named fields). Tuple variants
+/// are not allowed, and should result in a compile error, unless the leaf opts in with
+/// `allow_tuple`, e.g. `#[enum_tree_leaf(P, R, allow_tuple)]`; they are then leaf payloads, never
+/// child nodes. Leaves declared with `enum_tree!` or top-down opt in with
+/// `#[enum_tree(allow_tuple)]` instead.
+///
+/// A leaf may also be a struct or tuple struct, e.g.
+/// `#[enum_tree_leaf(P, R)] struct SetVolume { level: u8 }`, wrapped by its parent like any other
+/// child (`SetVolume(SetVolume)`). Root and inner nodes must be enums.
+///
+/// Generic parameters, lifetimes and where-clauses of the deriving enum are carried through to
+/// every generated impl, so the parent and root types may mention them, e.g.
+/// `#[enum_tree_leaf(Menu<T>, App<T>)]`. The variant a generic node names in its parent can't be
+/// checked where the node is declared: its `EnumTreeNodePath::PATH` and the conversions from the
+/// root fail to compile with the same error once they're used with concrete types.
+///
+/// # Conversions
+///
+/// For root nodes,  the parent type is `()`. Implementation of `ToEnumTreeRoot` and
+/// `TryFromEnumTreeRoot` for root types must be handled via macro expansion to avoid conflicting
+/// with the blanket impl in the `enum_tree` crate.
+///
+/// For inner and leaf nodes, implementation of `ToEnumTreeRoot` and `TryFromEnumTreeRoot` is
+/// handled via a blanket impl in the `enum_tree` crate, not via macro expansion.
 ///
+/// The parent derive implements `From<Child>` and `TryFrom<Parent> for Child` for each child,
+/// so every enum in a tree must derive `EnumTree`. A failed `TryFrom` returns the parent in a
+/// `Mismatch`, naming the expected child and the variant found instead.
+/// Every node also implements `TryFrom<Root>`; `TryFrom` comes only from the parent and the root,
+/// and other ancestors use `try_from_ancestor`. `From` only comes from the parent too: deeper
+/// descendants go up with `to_root` and `to_ancestor`, since a blanket `From<Descendant>` on every
+/// ancestor makes large trees slow to compile. `enum_tree!`, which sees the whole tree, implements
+/// `From<Descendant>` for every ancestor, so `?` and `impl Into<Root>` accept its nodes at any
+/// depth, and the matching `TryFrom<Ancestor>` for every descendant.
+/// Each node also steps to its first parent in every tree it declares, which `to_ancestor` and
+/// `try_from_ancestor` chain to reach any ancestor; top-down parents add the steps for their
+/// children.
 ///
-/// For root nodes,  the parent type is `()`. We will NOT implement `From` or `TryFrom` for the parent type, as there is no parent. Implementation of `ToEnumTreeRoot` and `TryFromEnumTreeRoot` for root types must be handled via macro expansion to avoid conflicting with the blanket impl in the `enum_tree` crate.
+/// Boxed and shared children are boxed or shared on the way up, and unboxed or cloned out
+/// (`unwrap_or_clone`) on the way down, so `Rc`/`Arc` children must be `Clone`.
+/// `TryFromParentRef`, behind `from_root_ref`/`from_root_mut`, borrows the child through the
+/// pointer instead; mutable access to a shared child goes through `make_mut`.
+/// For a variant carrying context, `TryFrom` and `from_root` skip the context, and
+/// `TryFromParentWithContext` / `TryFromEnumTreeRootWithContext` return it alongside the child;
+/// no `From<Child>` is generated for such a variant, since the context can't be made up.
+/// No `From<Child>` is generated either for a child held in several variants: `to_root_at` takes
+/// the position to wrap the child in, and `from_root_at` reports the variant it was found in.
+/// A node with several parents reaches the root through any of them with `to_root_via::<P>()`;
+/// `from_root`, `from_root_at` and `from_root_with_context` try each in turn, and `from_root_via`
+/// also returns the `EnumTreeNodePath::PATH` of the parent it was found under.
+/// `from_root_with_context` needs every parent to give the node the same context type.
 ///
-/// For inner and leaf nodes,
-/// as well as `From<P>` and `TryFrom<P>` for the parent enum type P.  Implementation of `ToEnumTreeRoot` and `TryFromEnumTreeRoot` for the all non-root is handled via a blanket impl in the `enum_tree` crate, not via macro expansion.
+/// # Reflection
 ///
-/// Inner nodes and the root node must have variants that wrap their child enum types;
-/// i.e., every variant must be a single tuple variant with one slot,
-/// and the type of that slot must be one of the child enum types.
-/// Variant names MUST match the name of the child enum type. (Failure should result in a compile error pointing to the offending variant.)
+/// Every node implements `EnumTreePath`, naming the variants a value holds down to its leaf
+/// variant, and `EnumTreeNodePath<R>`, whose `PATH` names the variants wrapping it from the root.
 ///
-/// Leaf nodes must have either unit variants, or struct variants (named fields). Tuple variants are not allowed, and should result in a compile error.
-#[proc_macro_derive(EnumTree, attributes(enum_tree_root, enum_tree_inner, enum_tree_leaf))]
+/// # Diagnostics
+///
+/// A child whose parent lacks the expected variant gets a single compile error pointing at the
+/// parent type in its attribute, naming the parent and the missing variant.
+/// Conversely, the root and inner nodes check that every child they wrap declares them as its
+/// parent, for every tree they belong to; a child that forgot its attribute or names another
+/// parent gets an error pointing at the wrapping variant.
+///
+/// Malformed attributes (`#[enum_tree_leaf(P)]`, unknown arguments) and conflicting ones
+/// (`#[enum_tree_root]` with `#[enum_tree_leaf]`) are reported as errors pointing at the
+/// attribute. All problems are reported together, and the impls for the valid attributes are
+/// still generated so they don't cause follow-on errors.
+///
+/// # Top-down trees
+///
+/// `#[enum_tree_root(top_down)]` makes the root declare its children instead of the children
+/// declaring their parent and root. Children then carry a bare `#[derive(EnumTree)]` with no
+/// attributes; an attribute-less enum with tuple variants wraps children and declares them the
+/// same way, while one with only unit and struct variants, or a struct, is a leaf. The parent
+/// generates, for every child-wrapping variant, `From`/`TryFrom` and
+/// `impl<R> EnumTree<R> for Child where Parent: EnumTree<R> { type P = Parent; }`, so a subtree
+/// can be moved by editing a single variant. Each top-down child below the root must have
+/// exactly one parent; several top-down roots may share a child. A top-down leaf with tuple
+/// payloads, e.g. `UpdatePortText(String)`, opts in with `#[enum_tree(allow_tuple)]`.
+#[proc_macro_derive(
+    EnumTree,
+    attributes(enum_tree_root, enum_tree_inner, enum_tree_leaf, enum_tree)
+)]
 pub fn enum_tree_derive(input: TokenStream) -> TokenStream {
     let derive_input = syn::parse_macro_input!(input as DeriveInput);
     TokenStream::from(expand_enum_tree(derive_input))
 }
 
-pub(crate) fn expand_enum_tree(input: DeriveInput) -> proc_macro2::TokenStream {
+/// Declares a whole enum tree in one block.
+///
+/// Each node `Name { .. }` becomes an enum, and every child node becomes a variant of its parent
+/// wrapping the child enum, with the same name. Items that are not nodes are ordinary variants:
+/// `Name`, `Name { field: Ty }` or `Name(Ty)`. A node holding only variants is a leaf; a node
+/// holding child nodes is an inner node, whose unit and struct variants are leaves attached to
+/// it. Every child is a node of the block: tuple variants of the root and inner nodes are
+/// rejected, and those of a leaf need `#[enum_tree(allow_tuple)]` in front of it.
+///
+/// ```ignore
+/// enum_tree! {
+///     #[derive(Debug, Clone, PartialEq)]
+///     pub AppAction {
+///         Menu {
+///             Settings { ToggleSound, Volume { level: u8 } },
+///         },
+///         Network { Connect { port: u16 }, Disconnect },
+///     }
+/// }
+/// ```
+///
+/// Attributes in front of the root apply to every generated enum, except doc comments, which
+/// stay on the root; attributes in front of a nested node apply to that node only. The
+/// visibility of the root applies to every enum. Node names must be unique within the tree.
+///
+/// Every ancestor implements `From` for each of its descendants, not only for its children, and
+/// each descendant implements `TryFrom` for every ancestor.
+///
+/// The root also implements `EnumTreeNodes`, listing the path of every node in declaration
+/// order.
+#[proc_macro]
+pub fn enum_tree(input: TokenStream) -> TokenStream {
+    let tree_input = syn::parse_macro_input!(input as tree_macro::TreeInput);
+    TokenStream::from(tree_macro::expand_enum_tree_macro(tree_input))
+}
+
+pub(crate) fn expand_enum_tree(mut input: DeriveInput) -> proc_macro2::TokenStream {
+    // `#[enum_tree(..)]` is sugar for the three positional attributes.
+    let mut errors = Errors::default();
+    attrs::desugar_enum_tree_attrs(&mut input, &mut errors);
+
     let attrs = &input.attrs;
     let mut is_root = false;
     let mut has_inner = false;
@@ -49,184 +202,1387 @@ pub(crate) fn expand_enum_tree(input: DeriveInput) -> proc_macro2::TokenStream {
         }
     }
 
-    if is_root {
-        return expand_enum_tree_root(input);
-    }
-    if has_inner {
-        return expand_enum_tree_inner(input);
+    if let Data::Union(data) = &input.data {
+        return syn::Error::new(
+            data.union_token.span,
+            "EnumTree cannot be derived for unions; use an enum or a struct",
+        )
+        .to_compile_error();
     }
-    if has_leaf {
-        return expand_enum_tree_leaf(input);
+
+    // Conflicting attributes are reported, and the enum is expanded as the strongest of them
+    // (root, then inner, then leaf) so that the valid impls are still generated.
+    attrs::check_attr_combination(&input, &mut errors);
+    let allow_tuple = attrs::allow_tuple_attr(&input);
+    if let Some(key) = &allow_tuple
+        && (is_root || has_inner)
+    {
+        errors.push(syn::Error::new(
+            key.span(),
+            "`allow_tuple` only applies to leaves; the tuple variants of root and inner nodes wrap children",
+        ));
     }
+    let mut tokens = errors.into_compile_error();
+
+    // Leaves hold no children, whatever their variants look like.
+    tokens.extend(expand_tree_path(
+        &input,
+        is_root || has_inner || (!has_leaf && allow_tuple.is_none()),
+    ));
 
-    syn::Error::new(
-        input.span(),
-        "EnumTree derive requires one of #[enum_tree_root], #[enum_tree_inner(P,R)], or #[enum_tree_leaf(P,R)]",
-    )
-    .to_compile_error()
+    if is_root {
+        tokens.extend(expand_enum_tree_root(input));
+    } else if has_inner {
+        tokens.extend(expand_enum_tree_inner(input));
+    } else if has_leaf {
+        tokens.extend(expand_enum_tree_leaf(input));
+    } else {
+        tokens.extend(expand_enum_tree_top_down(input));
+    }
+    tokens
 }
 
 pub(crate) fn expand_enum_tree_root(input: DeriveInput) -> proc_macro2::TokenStream {
-    let ident = input.ident.clone();
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let self_ty = quote!(#ident #ty_generics);
+
+    let krate = attrs::crate_path(&input);
+    let mut errors = Errors::default();
+    let root_attr = attrs::root_attr(&input, &mut errors);
+
+    // The root of one tree may also be mounted as an inner node of other trees.
+    let mounted = attrs::parent_attrs(&input, "enum_tree_inner", &mut errors);
+    let mounted_impls = expand_child_impls(
+        &input,
+        &mounted,
+        "enum_tree_inner",
+        quote!(#krate::EnumTreeInner),
+    );
+
+    // Children belong to this tree and to every tree the root itself is mounted in.
+    let mut roots = vec![self_ty.clone()];
+    for parent in &mounted {
+        let root = &parent.root;
+        let root = quote!(#root);
+        if !roots.iter().any(|r| r.to_string() == root.to_string()) {
+            roots.push(root);
+        }
+    }
+    let children = if root_attr.top_down {
+        expand_parent_impls(&input, ChildDecls::Roots(&roots), &mut errors)
+    } else {
+        // Self-declared children only have to declare this tree; they join the outer ones
+        // through `MountedIn`.
+        let decls = ChildDecls::SelfDeclared {
+            roots: &roots[..1],
+            is_root: true,
+        };
+        expand_parent_impls(&input, decls, &mut errors)
+    };
+    let mounted_in = roots[1..].iter().map(|root| {
+        quote! {
+            impl #impl_generics #krate::__private::MountedIn<#root> for #self_ty #where_clause {}
+        }
+    });
+    let mounted_child_from_root = roots[1..]
+        .iter()
+        .map(|root| expand_inner_child_from_root(&input, &input.generics, root));
+    let errors = errors.into_compile_error();
 
     quote! {
-        impl ::enum_tree::EnumTree<#ident> for #ident {
+        impl #impl_generics #krate::EnumTree<#self_ty> for #self_ty #where_clause {
             type P = ();
         }
-        impl ::enum_tree::EnumTreeRoot<#ident> for #ident {}
+        impl #impl_generics #krate::EnumTreeRoot<#self_ty> for #self_ty #where_clause {}
+        impl #impl_generics #krate::EnumTreeNodePath<#self_ty> for #self_ty #where_clause {
+            const PATH: &'static #krate::NodePath = &#krate::NodePath::ROOT;
+        }
 
-        impl ::enum_tree::ToEnumTreeRoot<#ident> for #ident {
-            fn to_root(self) -> #ident { self }
+        impl #impl_generics #krate::ToEnumTreeRoot<#self_ty> for #self_ty #where_clause {
+            fn to_root(self) -> #self_ty { self }
         }
 
-        impl ::enum_tree::TryFromEnumTreeRoot<#ident> for #ident {
-            fn from_root(root: #ident) -> Option<Self> { Some(root) }
+        impl #impl_generics #krate::__private::Ancestry<#self_ty> for #self_ty #where_clause {
+            type Depth = #krate::__private::Zero;
+            fn from_parents(root: #self_ty) -> ::core::result::Result<(Self, &'static #krate::NodePath), #krate::Mismatch<#self_ty>> { ::core::result::Result::Ok((root, &#krate::NodePath::ROOT)) }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree #self_ty) -> ::core::result::Result<&'__enum_tree Self, #krate::Mismatch<&'__enum_tree #self_ty>> { ::core::result::Result::Ok(root) }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut #self_ty) -> ::core::option::Option<&'__enum_tree mut Self> { ::core::option::Option::Some(root) }
+            fn from_parents_at(_: #self_ty) -> ::core::option::Option<(Self, &'static str)> { ::core::option::Option::None }
         }
+        impl #impl_generics #krate::__private::MountedIn<#self_ty> for #self_ty #where_clause {}
+
+        impl #impl_generics #krate::TryFromEnumTreeRootWithContext<#self_ty> for #self_ty #where_clause {
+            type Context = ();
+            fn from_root_with_context(root: #self_ty) -> ::core::option::Option<(Self, ())> { ::core::option::Option::Some((root, ())) }
+        }
+
+        impl #impl_generics #krate::__private::SingleRoot for #self_ty #where_clause {
+            type Root = #self_ty;
+        }
+
+        #mounted_impls
+        #(#mounted_in)*
+        #(#mounted_child_from_root)*
+        #children
+        #errors
     }
 }
 
 pub(crate) fn expand_enum_tree_inner(input: DeriveInput) -> proc_macro2::TokenStream {
-    use std::collections::HashSet;
-
-    let ident = input.ident.clone();
+    let krate = attrs::crate_path(&input);
+    let mut errors = Errors::default();
 
     // Collect all enum_tree_inner(P,R) attributes
-    let mut pairs: Vec<(Type, Type)> = Vec::new();
-    for attr in input.attrs.iter() {
-        if attr.path().is_ident("enum_tree_inner") {
-            pairs.push(parse_two_type_args(attr));
+    let parents = attrs::parent_attrs(&input, "enum_tree_inner", &mut errors);
+    if parents.is_empty() && errors.is_empty() {
+        errors.push(syn::Error::new(
+            input.span(),
+            "missing parent type for enum_tree_inner",
+        ));
+    }
+
+    let mut tokens = expand_child_impls(
+        &input,
+        &parents,
+        "enum_tree_inner",
+        quote!(#krate::EnumTreeInner),
+    );
+    // Children belong to every tree this node belongs to.
+    let mut roots: Vec<proc_macro2::TokenStream> = Vec::new();
+    for parent in &parents {
+        let root = &parent.root;
+        let root = quote!(#root);
+        if !roots.iter().any(|r| r.to_string() == root.to_string()) {
+            roots.push(root);
         }
     }
-    if pairs.is_empty() {
-        return syn::Error::new(input.span(), "missing parent type for enum_tree_inner")
-            .to_compile_error();
+    tokens.extend(expand_parent_impls(
+        &input,
+        ChildDecls::SelfDeclared {
+            roots: &roots,
+            is_root: false,
+        },
+        &mut errors,
+    ));
+    // Children of a node in a single tree are extracted through it in every tree its root is
+    // mounted in, the root's own included, like the node itself.
+    if let [root] = roots.as_slice() {
+        let root_param = root_param();
+        let mut generics = input.generics.clone();
+        generics.params.push(syn::parse_quote!(#root_param));
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#root: #krate::__private::MountedIn<#root_param>));
+        tokens.extend(expand_inner_child_from_root(&input, &generics, &root_param));
+    } else {
+        for root in &roots {
+            tokens.extend(expand_inner_child_from_root(&input, &input.generics, root));
+        }
     }
+    // Lets children of a node in a single tree leave out the root. Those of a node in several
+    // trees get one error from `assert_single_root` instead; the root they infer is local, so that
+    // the impls naming it can't overlap any other.
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    tokens.extend(match roots.as_slice() {
+        [root] => quote! {
+            impl #impl_generics #krate::__private::SingleRoot for #ident #ty_generics #where_clause {
+                type Root = #root;
+            }
+        },
+        _ => quote! {
+            const _: () = {
+                pub struct NoSingleRoot<T: ?Sized>(::core::marker::PhantomData<T>);
 
-    let variant_ident = &ident; // Variant name in parent equals enum name
+                impl #impl_generics #krate::__private::SingleRoot for #ident #ty_generics #where_clause {
+                    type Root = NoSingleRoot<Self>;
+                }
+            };
+        },
+    });
+    tokens.extend(errors.into_compile_error());
+    tokens
+}
 
-    // Generate impls for each (parent, root) pair
-    let mut enum_impls = Vec::new();
-    let mut from_impls = Vec::new();
-    let mut seen_parents: HashSet<String> = HashSet::new();
+pub(crate) fn expand_enum_tree_leaf(input: DeriveInput) -> proc_macro2::TokenStream {
+    let ident = &input.ident;
+    let krate = attrs::crate_path(&input);
+    let mut errors = Errors::default();
 
-    for (p_ty, r_ty) in &pairs {
-        enum_impls.push(quote! {
-            impl ::enum_tree::EnumTree<#r_ty> for #ident { type P = #p_ty; }
-            impl ::enum_tree::EnumTreeInner<#r_ty> for #ident {}
-        });
+    // Collect all enum_tree_leaf(P,R) attributes
+    let parents = attrs::parent_attrs(&input, "enum_tree_leaf", &mut errors);
+    if parents.is_empty() && errors.is_empty() {
+        errors.push(syn::Error::new(
+            input.span(),
+            "missing parent type for enum_tree_leaf",
+        ));
+    }
 
-        let p_str = type_key(p_ty);
-        if seen_parents.insert(p_str) {
-            from_impls.push(quote! {
-                impl From<#ident> for #p_ty {
-                    fn from(value: #ident) -> Self { #p_ty::#variant_ident(value) }
+    // Validate leaf enum variants: only unit or struct (named fields). Tuple variants are
+    // allowed as payloads only with `allow_tuple`.
+    let allow_tuple = parents.iter().any(|parent| parent.allow_tuple.is_some())
+        || attrs::allow_tuple_attr(&input).is_some();
+    if let Data::Enum(DataEnum { variants, .. }) = &input.data
+        && !allow_tuple
+    {
+        for v in variants {
+            match &v.fields {
+                Fields::Unnamed(_) => {
+                    let msg = format!(
+                        "EnumTree leaf '{}' cannot have tuple variants (found tuple variant '{}'); add `allow_tuple` to accept them as payloads",
+                        ident, v.ident
+                    );
+                    errors.push(syn::Error::new(v.span(), msg));
                 }
+                Fields::Named(_) | Fields::Unit => {}
+            }
+        }
+    }
+
+    let mut tokens = expand_child_impls(
+        &input,
+        &parents,
+        "enum_tree_leaf",
+        quote!(#krate::EnumTreeLeaf),
+    );
+    tokens.extend(errors.into_compile_error());
+    tokens
+}
+
+/// Expansion for an enum without tree attributes, whose parent declares it top-down.
+///
+/// Marks the enum as inner or leaf for every tree its parent belongs to, and declares its own
+/// children top-down if it has any.
+pub(crate) fn expand_enum_tree_top_down(input: DeriveInput) -> proc_macro2::TokenStream {
+    let ident = &input.ident;
+    let krate = attrs::crate_path(&input);
+    let mut errors = Errors::default();
+    // Structs are always leaves, and so are enums whose tuple variants are payloads. Marker errors
+    // are reported by `expand_parent_impls`.
+    let is_inner = match &input.data {
+        Data::Enum(_) if attrs::allow_tuple_attr(&input).is_some() => false,
+        Data::Enum(DataEnum { variants, .. }) => variants
+            .iter()
+            .any(|v| !matches!(variant_role(v, &mut Errors::default()), VariantRole::Leaf)),
+        _ => false,
+    };
+    let (marker, children) = if is_inner {
+        (
+            quote!(#krate::EnumTreeInner),
+            expand_parent_impls(&input, ChildDecls::ParentTrees, &mut errors),
+        )
+    } else {
+        (quote!(#krate::EnumTreeLeaf), quote!())
+    };
 
-                impl TryFrom<#p_ty> for #ident {
-                    type Error = ();
-                    fn try_from(value: #p_ty) -> Result<Self, Self::Error> {
-                        if let #p_ty::#variant_ident(v) = value { Ok(v) } else { Err(()) }
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let self_ty = quote!(#ident #ty_generics);
+    let root_param = root_param();
+    let mut generics = input.generics.clone();
+    generics.params.push(syn::parse_quote!(#root_param));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#self_ty: #krate::EnumTree<#root_param>));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let errors = errors.into_compile_error();
+
+    quote! {
+        impl #impl_generics #marker<#root_param> for #self_ty #where_clause {}
+        #children
+        #errors
+    }
+}
+
+/// `EnumTreePath` for any node: the variant `self` holds and, if `wraps_children`, the child in
+/// it.
+fn expand_tree_path(input: &DeriveInput, wraps_children: bool) -> proc_macro2::TokenStream {
+    let krate = attrs::crate_path(input);
+    let ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
+    let body = match &input.data {
+        Data::Enum(DataEnum { variants, .. }) if variants.is_empty() => quote!(match *self {}),
+        Data::Enum(DataEnum { variants, .. }) => {
+            let mut child_tys = Vec::new();
+            // Role errors are reported by the parent expansion.
+            let arms: Vec<_> = variants.iter().map(|v| {
+                let name = v.ident.to_string();
+                match variant_role(v, &mut Errors::default()) {
+                    VariantRole::Child(index) if wraps_children => {
+                        let ChildPosition {
+                            child_ty,
+                            extract,
+                            unwrap_ref,
+                            ..
+                        } = ChildPosition::new(ident, v, index);
+                        if !child_tys.iter().any(|ty| type_display(ty) == type_display(child_ty)) {
+                            child_tys.push(child_ty);
+                        }
+                        quote! {
+                            #extract => ::core::option::Option::Some((#name, ::core::option::Option::Some(#unwrap_ref)))
+                        }
+                    }
+                    _ => {
+                        let variant_ident = &v.ident;
+                        quote! {
+                            #ident::#variant_ident { .. } => ::core::option::Option::Some((#name, ::core::option::Option::None))
+                        }
                     }
                 }
-            });
+            }).collect();
+            // A child that doesn't derive `EnumTree` only disables the impl under `for<'a>`; the
+            // parent's check reports it.
+            let where_clause = generics.make_where_clause();
+            for child_ty in child_tys {
+                where_clause.predicates.push(syn::parse_quote! {
+                    for<'__enum_tree> #child_ty: #krate::EnumTreePath
+                });
+            }
+            quote!(match self { #(#arms,)* })
         }
+        _ => quote!(::core::option::Option::None),
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #krate::EnumTreePath for #ident #ty_generics #where_clause {
+            fn path_step(&self) -> ::core::option::Option<(&'static str, ::core::option::Option<&dyn #krate::EnumTreePath>)> {
+                #body
+            }
+        }
+    }
+}
+
+/// Generic parameter standing for "any root", in the impls of nodes that join every tree another
+/// node belongs to.
+fn root_param() -> Ident {
+    Ident::new("__EnumTreeRoot", proc_macro2::Span::call_site())
+}
+
+/// Generic parameter standing for "any parent" in the `ChildOf` impl of a node whose attributes
+/// don't parse.
+fn parent_param() -> Ident {
+    Ident::new("__EnumTreeParent", proc_macro2::Span::call_site())
+}
+
+/// Generic parameter standing for "any child" in the `ChildFromRoot` impls of inner nodes.
+fn child_param() -> Ident {
+    Ident::new("__EnumTreeChild", proc_macro2::Span::call_site())
+}
+
+/// How a node is extracted by value from the root under one of its parents.
+#[derive(Clone, Copy)]
+enum ParentStep {
+    /// Through the parent's `ChildFromRoot` impl, which the root and bottom-up inner nodes have
+    /// for the trees they declare.
+    ChildFromRoot,
+    /// Through the parent's `TryFrom` impl, for parents which aren't the root of the tree.
+    TryFrom,
+}
+
+/// `Ancestry` for `node_ty` in the tree rooted at `r_ty`: one below the depth of its first parent,
+/// and extracted from the root trying each of `p_tys` in turn.
+///
+/// `r_ty` may be a parameter of `generics`, for nodes that join every tree their root is mounted
+/// in. The bounds sit under `for<'a>`: a parent that doesn't wrap the node only disables the impl,
+/// and the parent's own check reports it.
+fn expand_ancestry<P: quote::ToTokens>(
+    krate: &proc_macro2::TokenStream,
+    generics: &syn::Generics,
+    node_ty: &impl quote::ToTokens,
+    r_ty: &impl quote::ToTokens,
+    p_tys: &[&P],
+    step: ParentStep,
+    checks: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let base_generics = generics;
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+    for p_ty in p_tys {
+        where_clause.predicates.push(match step {
+            ParentStep::ChildFromRoot => syn::parse_quote! {
+                for<'__enum_tree> #p_ty: #krate::__private::ChildFromRoot<#r_ty, #node_ty> + #krate::TryFromEnumTreeRootRef<#r_ty>
+            },
+            ParentStep::TryFrom => syn::parse_quote! {
+                for<'__enum_tree> #p_ty: #krate::TryFromEnumTreeRoot<#r_ty> + #krate::TryFromEnumTreeRootRef<#r_ty>
+            },
+        });
+        where_clause.predicates.push(syn::parse_quote! {
+            for<'__enum_tree> #p_ty: #krate::EnumTreeNodePath<#r_ty> + #krate::__private::ParentOf<#node_ty>
+        });
+        where_clause.predicates.push(match step {
+            ParentStep::ChildFromRoot => syn::parse_quote! {
+                for<'__enum_tree> #node_ty: #krate::TryFromParentRef<#p_ty>
+            },
+            ParentStep::TryFrom => syn::parse_quote! {
+                for<'__enum_tree> #node_ty: ::core::convert::TryFrom<#p_ty, Error = #krate::Mismatch<#p_ty>> + #krate::TryFromParentRef<#p_ty>
+            },
+        });
     }
+    let (first_p_ty, other_p_tys) = p_tys.split_first().unwrap();
+    where_clause.predicates.push(syn::parse_quote! {
+        for<'__enum_tree> #first_p_ty: #krate::__private::Ancestry<#r_ty>
+    });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
 
+    let steps: Vec<_> = p_tys
+        .iter()
+        .map(|p_ty| match step {
+            ParentStep::ChildFromRoot => quote! {
+                <#p_ty as #krate::__private::ChildFromRoot<#r_ty, Self>>::child_from_root
+            },
+            ParentStep::TryFrom => quote! {
+                #krate::__private::child_from_root::<#r_ty, #p_ty, Self>
+            },
+        })
+        .collect();
+    let (last_step, init_steps) = steps.split_last().unwrap();
+    let (first_step, steps) = steps.split_first().unwrap();
+    let with_context =
+        expand_from_parents_with_context(krate, base_generics, node_ty, r_ty, p_tys, checks);
+    // A mutable borrow can't be handed on to the next parent: the one holding the node is found
+    // on a shared borrow first.
+    let (last_p_ty, init_p_tys) = p_tys.split_last().unwrap();
     quote! {
-        #(#enum_impls)*
-        #(#from_impls)*
+        impl #impl_generics #krate::__private::Ancestry<#r_ty> for #node_ty #where_clause {
+            type Depth = #krate::__private::Succ<<#first_p_ty as #krate::__private::Ancestry<#r_ty>>::Depth>;
+            fn from_parents(root: #r_ty) -> ::core::result::Result<(Self, &'static #krate::NodePath), #krate::Mismatch<#r_ty>> {
+                #checks
+                let mismatch = match #first_step(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <#first_p_ty as #krate::EnumTreeNodePath<#r_ty>>::PATH)),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                #(
+                    let (expected, found) = (mismatch.expected(), mismatch.found());
+                    let mismatch = match #steps(mismatch.into_inner()) {
+                        ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <#other_p_tys as #krate::EnumTreeNodePath<#r_ty>>::PATH)),
+                        ::core::result::Result::Err(next) => #krate::Mismatch::new(next.into_inner(), expected, found),
+                    };
+                )*
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree #r_ty) -> ::core::result::Result<&'__enum_tree Self, #krate::Mismatch<&'__enum_tree #r_ty>> {
+                #checks
+                let mismatch = match #krate::__private::child_from_root_ref::<#r_ty, #first_p_ty, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                #(
+                    if let ::core::result::Result::Ok(node) = #krate::__private::child_from_root_ref::<#r_ty, #other_p_tys, Self>(root) {
+                        return ::core::result::Result::Ok(node);
+                    }
+                )*
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut #r_ty) -> ::core::option::Option<&'__enum_tree mut Self> {
+                #checks
+                #(
+                    if #krate::__private::child_from_root_ref::<#r_ty, #init_p_tys, Self>(root).is_ok() {
+                        return #krate::__private::child_from_root_mut::<#r_ty, #init_p_tys, Self>(root);
+                    }
+                )*
+                #krate::__private::child_from_root_mut::<#r_ty, #last_p_ty, Self>(root)
+            }
+            fn from_parents_at(root: #r_ty) -> ::core::option::Option<(Self, &'static str)> {
+                #checks
+                #(
+                    if let ::core::option::Option::Some(position) = #krate::__private::position_in_root::<#r_ty, #init_p_tys, Self>(&root) {
+                        return #init_steps(root).ok().map(|node| (node, position));
+                    }
+                )*
+                let position = #krate::__private::position_in_root::<#r_ty, #last_p_ty, Self>(&root)?;
+                #last_step(root).ok().map(|node| (node, position))
+            }
+        }
+        #with_context
     }
 }
 
-pub(crate) fn expand_enum_tree_leaf(input: DeriveInput) -> proc_macro2::TokenStream {
-    use std::collections::HashSet;
+/// `FromParentsWithContext` for `node_ty` in the tree rooted at `r_ty`, trying each of `p_tys` in
+/// turn. The parents after the first must give the node the same context type as the first one;
+/// if one doesn't, only this impl is disabled.
+fn expand_from_parents_with_context<P: quote::ToTokens>(
+    krate: &proc_macro2::TokenStream,
+    generics: &syn::Generics,
+    node_ty: &impl quote::ToTokens,
+    r_ty: &impl quote::ToTokens,
+    p_tys: &[&P],
+    checks: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (first_p_ty, other_p_tys) = p_tys.split_first().unwrap();
+    let p_context = quote!(<#first_p_ty as #krate::TryFromEnumTreeRootWithContext<#r_ty>>::Context);
+    let context = quote!(<#node_ty as #krate::TryFromParentWithContext<#first_p_ty>>::Context);
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+    where_clause.predicates.push(syn::parse_quote! {
+        for<'__enum_tree> #first_p_ty: #krate::TryFromEnumTreeRootWithContext<#r_ty> + #krate::TryFromEnumTreeRootRef<#r_ty>
+    });
+    where_clause.predicates.push(syn::parse_quote! {
+        for<'__enum_tree> #node_ty: #krate::TryFromParentWithContext<#first_p_ty> + #krate::TryFromParentRef<#first_p_ty>
+    });
+    for p_ty in other_p_tys {
+        where_clause.predicates.push(syn::parse_quote! {
+            for<'__enum_tree> #p_ty: #krate::TryFromEnumTreeRootWithContext<#r_ty, Context = #p_context> + #krate::TryFromEnumTreeRootRef<#r_ty>
+        });
+        where_clause.predicates.push(syn::parse_quote! {
+            for<'__enum_tree> #node_ty: #krate::TryFromParentWithContext<#p_ty, Context = #context> + #krate::TryFromParentRef<#p_ty>
+        });
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (last_p_ty, init_p_tys) = p_tys.split_last().unwrap();
+    quote! {
+        impl #impl_generics #krate::__private::FromParentsWithContext<#r_ty> for #node_ty #where_clause {
+            type Context = (#p_context, #context);
+            fn from_parents_with_context(root: #r_ty) -> ::core::option::Option<(Self, Self::Context)> {
+                #checks
+                #(
+                    if #krate::__private::child_from_root_ref::<#r_ty, #init_p_tys, Self>(&root).is_ok() {
+                        return #krate::__private::child_from_root_with_context::<#r_ty, #init_p_tys, Self>(root);
+                    }
+                )*
+                #krate::__private::child_from_root_with_context::<#r_ty, #last_p_ty, Self>(root)
+            }
+        }
+    }
+}
 
-    let ident = input.ident.clone();
+/// `ChildFromRoot` for an inner node of the tree rooted at `r_ty`, covering all of its children.
+///
+/// `r_ty` may be a parameter of `generics`, for the trees the node's own root is mounted in.
+fn expand_inner_child_from_root(
+    input: &DeriveInput,
+    generics: &syn::Generics,
+    r_ty: &impl quote::ToTokens,
+) -> proc_macro2::TokenStream {
+    let krate = attrs::crate_path(input);
+    let ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let self_ty = quote!(#ident #ty_generics);
+    let child = child_param();
+    let mut generics = generics.clone();
+    generics.params.push(syn::parse_quote!(#child));
+    let where_clause = generics.make_where_clause();
+    where_clause.predicates.push(syn::parse_quote! {
+        #child: ::core::convert::TryFrom<#self_ty, Error = #krate::Mismatch<#self_ty>> + #krate::TryFromParentRef<#self_ty>
+    });
+    // Only holds once this node's own parent wraps it; see `expand_child_impls`.
+    where_clause.predicates.push(syn::parse_quote! {
+        for<'__enum_tree> #self_ty: #krate::TryFromEnumTreeRoot<#r_ty> + #krate::TryFromEnumTreeRootRef<#r_ty>
+    });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #krate::__private::ChildFromRoot<#r_ty, #child> for #self_ty #where_clause {
+            fn child_from_root(root: #r_ty) -> ::core::result::Result<#child, #krate::Mismatch<#r_ty>> {
+                #krate::__private::child_from_root::<#r_ty, #self_ty, #child>(root)
+            }
+        }
+    }
+}
 
-    // Validate leaf enum variants: only unit or struct (named fields). No tuple variants allowed.
-    if let Data::Enum(DataEnum { variants, .. }) = &input.data {
-        for v in variants {
-            match &v.fields {
-                Fields::Unnamed(_) => {
-                    let msg = format!(
-                        "EnumTree leaf '{}' cannot have tuple variants (found tuple variant '{}')",
-                        ident, v.ident
-                    );
-                    return syn::Error::new(v.span(), msg).to_compile_error();
+/// How a parent's derive declares the place of its children in the tree.
+enum ChildDecls<'a> {
+    /// Children declare their own parent and root with `#[enum_tree_inner]`/`#[enum_tree_leaf]`;
+    /// the parent checks that they do so for each of these trees. Children of the root get
+    /// their `TryFrom<Root>` from their own derive, through the root's `ChildFromRoot`.
+    SelfDeclared {
+        roots: &'a [proc_macro2::TokenStream],
+        is_root: bool,
+    },
+    /// Top-down root: children join exactly these trees.
+    Roots(&'a [proc_macro2::TokenStream]),
+    /// Top-down inner node: children join every tree the parent belongs to.
+    ParentTrees,
+}
+
+/// Parent-side expansion: for every variant `V(Child)` wrapping a child, the `From`/`TryFrom`
+/// conversions between parent and child, `TryFromParentWithContext`, and the `ParentOf<Child>`
+/// impl children check their declared parent against. For top-down trees, also the child's own
+/// `EnumTree` impls; otherwise a check that each child declares this enum as its parent.
+///
+/// Conversions are generated by the parent rather than by each child, so a parent whose
+/// variants don't match what a child expects yields a single targeted error from the child.
+///
+/// A child type held in several variants gets one set of impls covering all of its positions,
+/// without `From`, since the position to wrap it in is ambiguous.
+fn expand_parent_impls(
+    input: &DeriveInput,
+    decls: ChildDecls,
+    errors: &mut Errors,
+) -> proc_macro2::TokenStream {
+    let krate = attrs::crate_path(input);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let self_ty = quote!(#ident #ty_generics);
+
+    let Data::Enum(DataEnum { variants, .. }) = &input.data else {
+        errors.push(syn::Error::new(
+            input.span(),
+            "EnumTree root and inner nodes must be enums; only leaves may be structs",
+        ));
+        return quote!();
+    };
+
+    // Positions grouped by child type, in declaration order.
+    let mut children: Vec<(String, Vec<ChildPosition>)> = Vec::new();
+    for v in variants {
+        let index = match variant_role(v, errors) {
+            VariantRole::Child(index) => index,
+            VariantRole::Ambiguous if !matches!(decls, ChildDecls::SelfDeclared { .. }) => {
+                let msg = format!(
+                    "EnumTree top-down parent '{}' must wrap a single child type in every tuple variant (found variant '{}'); mark the child field with `#[enum_tree(child)]`",
+                    ident, v.ident
+                );
+                errors.push(syn::Error::new(v.span(), msg));
+                continue;
+            }
+            VariantRole::Ambiguous | VariantRole::Leaf => continue,
+        };
+        let position = ChildPosition::new(ident, v, index);
+        let key = type_display(position.child_ty);
+        match children.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, positions)) => positions.push(position),
+            None => children.push((key, vec![position])),
+        }
+    }
+
+    // The root's children implement `TryFrom<Root>` in their own derive, on top of this step.
+    let extracts_from_root = matches!(decls, ChildDecls::SelfDeclared { is_root: true, .. });
+    let try_from_impl = |child_ty: &Type, body: proc_macro2::TokenStream| {
+        if extracts_from_root {
+            quote! {
+                impl #impl_generics #krate::__private::ChildFromRoot<#self_ty, #child_ty> for #self_ty #where_clause {
+                    fn child_from_root(value: #self_ty) -> ::core::result::Result<#child_ty, #krate::Mismatch<#self_ty>> { #body }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics ::core::convert::TryFrom<#self_ty> for #child_ty #where_clause {
+                    type Error = #krate::Mismatch<#self_ty>;
+                    fn try_from(value: #self_ty) -> ::core::result::Result<Self, Self::Error> { #body }
+                }
+            }
+        }
+    };
+
+    // Hands `value` back when it holds something else than the child, naming the variant the
+    // child sits in and the one held instead.
+    let mismatch =
+        |value: proc_macro2::TokenStream, by_ref: proc_macro2::TokenStream, expected: &str| {
+            quote! {
+                {
+                    let found = <#self_ty as #krate::__private::VariantName>::variant_name(#by_ref);
+                    ::core::result::Result::Err(#krate::Mismatch::new(#value, #expected, found))
+                }
+            }
+        };
+
+    let mut tokens = proc_macro2::TokenStream::new();
+    for (_, positions) in children.iter() {
+        let first = &positions[0];
+        let child_ty = first.child_ty;
+        let first_name = first.variant.ident.to_string();
+        let value_mismatch = mismatch(quote!(value), quote!(&value), &first_name);
+        let ref_mismatch = mismatch(quote!(parent), quote!(parent), &first_name);
+        let mut_mismatch = mismatch(quote!(parent), quote!(&*parent), &first_name);
+
+        match decls {
+            ChildDecls::SelfDeclared { roots, .. } => {
+                // Errors point at the variant wrapping the child.
+                for r_ty in roots {
+                    tokens.extend(static_check(
+                        input,
+                        first.variant.span(),
+                        quote! {
+                            #krate::__private::assert_child_of::<#child_ty, #self_ty, #r_ty>()
+                        },
+                    ));
+                }
+            }
+            ChildDecls::Roots(roots) => {
+                for r_ty in roots {
+                    tokens.extend(quote! {
+                        impl #impl_generics #krate::EnumTree<#r_ty> for #child_ty #where_clause { type P = #self_ty; }
+                        impl #impl_generics #krate::EnumTreeNodePath<#r_ty> for #child_ty #where_clause {
+                            const PATH: &'static #krate::NodePath = &#krate::NodePath::child(<#self_ty as #krate::EnumTreeNodePath<#r_ty>>::PATH, #first_name);
+                        }
+                    });
+                    tokens.extend(expand_ancestry(
+                        &krate,
+                        &input.generics,
+                        child_ty,
+                        r_ty,
+                        &[&self_ty],
+                        ParentStep::TryFrom,
+                        &quote!(),
+                    ));
+                }
+            }
+            ChildDecls::ParentTrees => {
+                let root_param = root_param();
+                let mut generics = input.generics.clone();
+                generics.params.push(syn::parse_quote!(#root_param));
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(syn::parse_quote!(#self_ty: #krate::EnumTree<#root_param>));
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                tokens.extend(quote! {
+                    impl #impl_generics #krate::EnumTree<#root_param> for #child_ty #where_clause { type P = #self_ty; }
+                });
+                let mut path_generics = generics.clone();
+                path_generics
+                    .make_where_clause()
+                    .predicates
+                    .push(syn::parse_quote!(#self_ty: #krate::EnumTreeNodePath<#root_param>));
+                let (impl_generics, _, where_clause) = path_generics.split_for_impl();
+                tokens.extend(quote! {
+                    impl #impl_generics #krate::EnumTreeNodePath<#root_param> for #child_ty #where_clause {
+                        const PATH: &'static #krate::NodePath = &#krate::NodePath::child(<#self_ty as #krate::EnumTreeNodePath<#root_param>>::PATH, #first_name);
+                    }
+                });
+                tokens.extend(expand_ancestry(
+                    &krate,
+                    &generics,
+                    child_ty,
+                    &root_param,
+                    &[&self_ty],
+                    ParentStep::TryFrom,
+                    &quote!(),
+                ));
+            }
+        }
+
+        if let [position] = positions.as_slice() {
+            let ChildPosition {
+                variant,
+                wrap,
+                unwrap,
+                unwrap_ref,
+                unwrap_mut,
+                extract,
+                with_context,
+                context_tys,
+                context_vars,
+                ..
+            } = position;
+            let variant_ident = &variant.ident;
+            let variant_name = variant_ident.to_string();
+
+            // A child stored next to context fields can't be converted up: the context is unknown.
+            if context_vars.is_empty() {
+                tokens.extend(quote! {
+                    impl #impl_generics ::core::convert::From<#child_ty> for #self_ty #where_clause {
+                        fn from(value: #child_ty) -> Self { Self::#variant_ident(#wrap) }
+                    }
+                });
+            }
+
+            tokens.extend(try_from_impl(
+                child_ty,
+                quote! {
+                    if let #extract = value { ::core::result::Result::Ok(#unwrap) } else #value_mismatch
+                },
+            ));
+            tokens.extend(quote! {
+                impl #impl_generics #krate::__private::ParentOf<#child_ty> for #self_ty #where_clause {
+                    const VARIANTS: &'static [&'static str] = &[#variant_name];
+                }
+
+                impl #impl_generics #krate::TryFromParentWithContext<#self_ty> for #child_ty #where_clause {
+                    type Context = (#(#context_tys,)*);
+                    fn try_from_parent_with_context(parent: #self_ty) -> ::core::option::Option<(Self, Self::Context)> {
+                        if let #with_context = parent { ::core::option::Option::Some((#unwrap, (#(#context_vars,)*))) } else { ::core::option::Option::None }
+                    }
+                }
+
+                impl #impl_generics #krate::TryFromParentRef<#self_ty> for #child_ty #where_clause {
+                    fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree #self_ty) -> ::core::result::Result<&'__enum_tree Self, #krate::Mismatch<&'__enum_tree #self_ty>> {
+                        if let #extract = parent { ::core::result::Result::Ok(#unwrap_ref) } else #ref_mismatch
+                    }
+                    fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut #self_ty) -> ::core::result::Result<&'__enum_tree mut Self, #krate::Mismatch<&'__enum_tree mut #self_ty>> {
+                        if let #extract = parent { ::core::result::Result::Ok(#unwrap_mut) } else #mut_mismatch
+                    }
+                }
+            });
+            continue;
+        }
+
+        let variant_idents = positions.iter().map(|p| &p.variant.ident);
+        let variant_names = positions.iter().map(|p| p.variant.ident.to_string());
+        let variant_names2 = variant_names.clone();
+        let extracts = positions.iter().map(|p| &p.extract);
+        let unwraps = positions.iter().map(|p| &p.unwrap);
+        tokens.extend(try_from_impl(
+            child_ty,
+            quote! {
+                match value {
+                    #(#extracts => ::core::result::Result::Ok(#unwraps),)*
+                    #[allow(unreachable_patterns)]
+                    _ => #value_mismatch,
+                }
+            },
+        ));
+        tokens.extend(quote! {
+            impl #impl_generics #krate::__private::ParentOf<#child_ty> for #self_ty #where_clause {
+                const VARIANTS: &'static [&'static str] = &[#(#variant_names),*];
+                fn position(&self) -> &'static str {
+                    match self {
+                        #(#ident::#variant_idents { .. } => #variant_names2,)*
+                        #[allow(unreachable_patterns)]
+                        _ => #first_name,
+                    }
                 }
-                Fields::Named(_) | Fields::Unit => {}
             }
+        });
+
+        // All positions must agree on the context type for a single `Context`.
+        let context_ty = |p: &ChildPosition| {
+            let tys = &p.context_tys;
+            type_display(&quote!((#(#tys,)*)))
+        };
+        if positions.iter().all(|p| context_ty(p) == context_ty(first)) {
+            let context_tys = &first.context_tys;
+            let with_contexts = positions.iter().map(|p| &p.with_context);
+            let unwraps = positions.iter().map(|p| &p.unwrap);
+            let context_vars = positions.iter().map(|p| &p.context_vars);
+            tokens.extend(quote! {
+                impl #impl_generics #krate::TryFromParentWithContext<#self_ty> for #child_ty #where_clause {
+                    type Context = (#(#context_tys,)*);
+                    fn try_from_parent_with_context(parent: #self_ty) -> ::core::option::Option<(Self, Self::Context)> {
+                        match parent {
+                            #(#with_contexts => ::core::option::Option::Some((#unwraps, (#(#context_vars,)*))),)*
+                            #[allow(unreachable_patterns)]
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            });
         }
+
+        let extracts = positions.iter().map(|p| &p.extract);
+        let extracts2 = extracts.clone();
+        let unwrap_refs = positions.iter().map(|p| &p.unwrap_ref);
+        let unwrap_muts = positions.iter().map(|p| &p.unwrap_mut);
+        tokens.extend(quote! {
+            impl #impl_generics #krate::TryFromParentRef<#self_ty> for #child_ty #where_clause {
+                fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree #self_ty) -> ::core::result::Result<&'__enum_tree Self, #krate::Mismatch<&'__enum_tree #self_ty>> {
+                    match parent {
+                        #(#extracts => ::core::result::Result::Ok(#unwrap_refs),)*
+                        #[allow(unreachable_patterns)]
+                        _ => #ref_mismatch,
+                    }
+                }
+                fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut #self_ty) -> ::core::result::Result<&'__enum_tree mut Self, #krate::Mismatch<&'__enum_tree mut #self_ty>> {
+                    match parent {
+                        #(#extracts2 => ::core::result::Result::Ok(#unwrap_muts),)*
+                        #[allow(unreachable_patterns)]
+                        _ => #mut_mismatch,
+                    }
+                }
+            }
+        });
     }
 
-    // Collect all enum_tree_leaf(P,R) attributes
-    let mut pairs: Vec<(Type, Type)> = Vec::new();
-    for attr in input.attrs.iter() {
-        if attr.path().is_ident("enum_tree_leaf") {
-            pairs.push(parse_two_type_args(attr));
+    if !children.is_empty() {
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        let variant_names = variants.iter().map(|v| v.ident.to_string());
+        tokens.extend(quote! {
+            impl #impl_generics #krate::__private::VariantName for #self_ty #where_clause {
+                fn variant_name(&self) -> &'static str {
+                    match self {
+                        #(#ident::#variant_idents { .. } => #variant_names,)*
+                    }
+                }
+            }
+        });
+    }
+    tokens
+}
+
+/// A parent variant holding a child, with the patterns and expressions converting to and from it.
+struct ChildPosition<'a> {
+    variant: &'a syn::Variant,
+    child_ty: &'a Type,
+    /// Builds the child's field from `value`.
+    wrap: proc_macro2::TokenStream,
+    /// Gets the child out of the field bound to `v`.
+    unwrap: proc_macro2::TokenStream,
+    /// Borrows the child from the field bound to `v` by reference, as in `&parent` matches.
+    unwrap_ref: proc_macro2::TokenStream,
+    /// Borrows the child mutably from the field bound to `v` by mutable reference.
+    unwrap_mut: proc_macro2::TokenStream,
+    /// Binds the child field to `v`, skipping the context fields.
+    extract: proc_macro2::TokenStream,
+    /// Binds the child field to `v` and the context fields to `c0, c1, ..`.
+    with_context: proc_macro2::TokenStream,
+    context_tys: Vec<&'a Type>,
+    context_vars: Vec<Ident>,
+}
+
+impl<'a> ChildPosition<'a> {
+    fn new(ident: &Ident, variant: &'a syn::Variant, index: usize) -> Self {
+        let variant_ident = &variant.ident;
+        let fields: Vec<&syn::Field> = variant.fields.iter().collect();
+        let slot_ty = &fields[index].ty;
+        let (child_ty, slot) = child_slot(slot_ty);
+        let (wrap, unwrap, unwrap_ref, unwrap_mut) = match slot {
+            Slot::Direct => (quote!(value), quote!(v), quote!(v), quote!(v)),
+            Slot::Boxed => (
+                quote!(<#slot_ty>::new(value)),
+                quote!(*v),
+                quote!(&**v),
+                quote!(&mut **v),
+            ),
+            Slot::Shared => (
+                quote!(<#slot_ty>::new(value)),
+                quote!(<#slot_ty>::unwrap_or_clone(v)),
+                quote!(&**v),
+                quote!(<#slot_ty>::make_mut(v)),
+            ),
+        };
+
+        let mut extract = Vec::new();
+        let mut with_context = Vec::new();
+        let mut context_tys = Vec::new();
+        let mut context_vars = Vec::new();
+        for (i, field) in fields.iter().enumerate() {
+            let var = if i == index {
+                quote!(v)
+            } else {
+                let var = quote::format_ident!("c{}", context_vars.len());
+                context_tys.push(&field.ty);
+                context_vars.push(var.clone());
+                quote!(#var)
+            };
+            match &field.ident {
+                Some(name) if i == index => extract.push(quote!(#name: v)),
+                Some(_) => {}
+                None if i == index => extract.push(quote!(v)),
+                None => extract.push(quote!(_)),
+            }
+            match &field.ident {
+                Some(name) => with_context.push(quote!(#name: #var)),
+                None => with_context.push(var),
+            }
+        }
+        let (extract, with_context) = match &variant.fields {
+            Fields::Named(_) if context_vars.is_empty() => (
+                quote!(#ident::#variant_ident { #(#extract),* }),
+                quote!(#ident::#variant_ident { #(#with_context),* }),
+            ),
+            Fields::Named(_) => (
+                quote!(#ident::#variant_ident { #(#extract,)* .. }),
+                quote!(#ident::#variant_ident { #(#with_context),* }),
+            ),
+            _ => (
+                quote!(#ident::#variant_ident(#(#extract),*)),
+                quote!(#ident::#variant_ident(#(#with_context),*)),
+            ),
+        };
+
+        ChildPosition {
+            variant,
+            child_ty,
+            wrap,
+            unwrap,
+            unwrap_ref,
+            unwrap_mut,
+            extract,
+            with_context,
+            context_tys,
+            context_vars,
         }
     }
-    if pairs.is_empty() {
-        return syn::Error::new(input.span(), "missing parent type for enum_tree_leaf")
-            .to_compile_error();
+}
+
+/// What a variant of a parent enum holds.
+enum VariantRole {
+    /// A unit or struct variant: a leaf attached directly to the parent.
+    Leaf,
+    /// A child, in the field at this position: the only field of a single-field tuple variant,
+    /// or the field marked `#[enum_tree(child)]`.
+    Child(usize),
+    /// A tuple variant with several fields, none of them marked.
+    Ambiguous,
+}
+
+fn variant_role(v: &syn::Variant, errors: &mut Errors) -> VariantRole {
+    if let Some(index) = attrs::marked_child_field(&v.fields, errors) {
+        return VariantRole::Child(index);
+    }
+    match &v.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => VariantRole::Child(0),
+        Fields::Unnamed(_) => VariantRole::Ambiguous,
+        Fields::Named(_) | Fields::Unit => VariantRole::Leaf,
     }
+}
 
-    let parent_variant_ident = &ident; // Variant name in parent equals child enum name
+/// How a parent variant holds its child.
+enum Slot {
+    /// `V(Child)`
+    Direct,
+    /// `V(Box<Child>)`
+    Boxed,
+    /// `V(Rc<Child>)` or `V(Arc<Child>)`; the child is cloned out unless the pointer is unique.
+    Shared,
+}
 
-    let mut enum_impls = Vec::new();
-    let mut from_impls = Vec::new();
-    let mut seen_parents: HashSet<String> = HashSet::new();
+/// Splits a parent variant's field type into the child type and the pointer holding it.
+///
+/// Pointers are recognized by the last path segment, so `std::sync::Arc<Child>` and a
+/// re-exported `Arc<Child>` are treated alike.
+fn child_slot(ty: &Type) -> (&Type, Slot) {
+    if let Type::Path(type_path) = ty
+        && type_path.qself.is_none()
+        && let Some(seg) = type_path.path.segments.last()
+        && let syn::PathArguments::AngleBracketed(args) = &seg.arguments
+        && args.args.len() == 1
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        if seg.ident == "Box" {
+            return (inner, Slot::Boxed);
+        }
+        if seg.ident == "Rc" || seg.ident == "Arc" {
+            return (inner, Slot::Shared);
+        }
+    }
+    (ty, Slot::Direct)
+}
 
-    for (p_ty, r_ty) in &pairs {
+/// Child-side expansion shared by inner and leaf nodes: `EnumTree<R>` plus the given marker
+/// trait for every (parent, root) pair, and a check that each parent wraps this node in the
+/// expected variant.
+fn expand_child_impls(
+    input: &DeriveInput,
+    parents: &[ParentAttr],
+    attr_name: &str,
+    marker: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    use std::collections::HashSet;
+
+    let krate = attrs::crate_path(input);
+    let ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let self_ty = quote!(#ident #ty_generics);
+
+    // Generate impls for each tree the node declares. A node with several parents in the same
+    // tree takes the first one as its `EnumTree::P`; the others are reached with `to_root_via`
+    // and `from_root_via`.
+    let mut enum_impls = Vec::new();
+    let mut trees: Vec<TreeParents> = Vec::new();
+    let mut parent_checks = Vec::new();
+    // Generic nodes can't check their variant names in a constant of their own; the conversions
+    // through their parents check them instead, in inline constants, once the node is used.
+    let generic = !input.generics.params.is_empty();
+    // The same parent listed for several roots is checked once. Parents are compared as written:
+    // `a::Menu` and `b::Menu` are both checked, and so are aliases like `super::Menu` and
+    // `crate::Menu`, which is harmless since the check emits no impls that could conflict.
+    let mut seen_parents: HashSet<(String, String)> = HashSet::new();
+    // A node whose attributes don't parse is taken as a child of every parent, so that the
+    // parents meant in them don't report it on top of the attribute error.
+    let malformed = attrs::has_malformed_parent_attr(input);
+    if malformed {
+        let parent_param = parent_param();
+        let root_param = root_param();
+        let mut generics = input.generics.clone();
+        generics.params.push(syn::parse_quote!(#parent_param));
+        generics.params.push(syn::parse_quote!(#root_param));
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
         enum_impls.push(quote! {
-            impl ::enum_tree::EnumTree<#r_ty> for #ident { type P = #p_ty; }
-            impl ::enum_tree::EnumTreeLeaf<#r_ty> for #ident {}
+            impl #impl_generics #krate::__private::ChildOf<#parent_param, #root_param> for #self_ty #where_clause {}
         });
+    }
 
-        let p_str = type_key(p_ty);
-        if seen_parents.insert(p_str) {
-            from_impls.push(quote! {
-                impl From<#ident> for #p_ty {
-                    fn from(value: #ident) -> Self { #p_ty::#parent_variant_ident(value) }
-                }
+    for ParentAttr {
+        parent: p_ty,
+        root: r_ty,
+        root_inferred,
+        variant,
+        ..
+    } in parents
+    {
+        // Variant name in parent equals child enum name unless overridden
+        let variant_ident = variant.as_ref().unwrap_or(ident);
+        let variant_name = variant_ident.to_string();
+        let message = format!(
+            "parent `{}` has no variant `{}({})` required by `#[{}]`",
+            type_display(p_ty),
+            variant_name,
+            type_display(&self_ty),
+            attr_name,
+        );
+        let (path_variant, variant_check) = if generic {
+            let krate = respan(&krate, p_ty.span());
+            (
+                quote_spanned! {p_ty.span()=>
+                    #krate::__private::parent_variant::<#p_ty, #self_ty>(#variant_name, #message)
+                },
+                quote_spanned! {p_ty.span()=>
+                    const { #krate::__private::assert_parent_variant::<#p_ty, #self_ty>(#variant_name, #message) };
+                },
+            )
+        } else {
+            (quote!(#variant_name), quote!())
+        };
 
-                impl TryFrom<#p_ty> for #ident {
-                    type Error = ();
-                    fn try_from(value: #p_ty) -> Result<Self, Self::Error> {
-                        if let #p_ty::#parent_variant_ident(v) = value { Ok(v) } else { Err(()) }
-                    }
+        let key = type_display(r_ty);
+        let tree = match trees.iter_mut().find(|tree| tree.key == key) {
+            Some(tree) => tree,
+            None => {
+                trees.push(TreeParents {
+                    key,
+                    root: r_ty,
+                    root_inferred: *root_inferred,
+                    parents: Vec::new(),
+                    path_variant,
+                    variant_checks: proc_macro2::TokenStream::new(),
+                });
+                trees.last_mut().unwrap()
+            }
+        };
+        tree.parents.push(p_ty);
+        tree.variant_checks.extend(variant_check);
+        if *root_inferred && !malformed {
+            // Declared in every tree of the parent, so that a parent whose root can't be inferred
+            // doesn't report its children on top of `assert_single_root`.
+            let root_param = root_param();
+            let mut generics = input.generics.clone();
+            generics.params.push(syn::parse_quote!(#root_param));
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#p_ty: #krate::EnumTree<#root_param>));
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+            enum_impls.push(quote! {
+                impl #impl_generics #krate::__private::ChildOf<#p_ty, #root_param> for #self_ty #where_clause {}
+            });
+        }
+
+        if seen_parents.insert((type_display(p_ty), variant_name.clone())) {
+            // Errors point at the parent type in the attribute. The variant name can only be
+            // compared in a constant, which generic nodes can't have; here they only check that
+            // the parent wraps them at all.
+            parent_checks.push(if !generic {
+                // The whole call, crate path included, must carry the span.
+                let krate = respan(&krate, p_ty.span());
+                quote_spanned! {p_ty.span()=>
+                    const _: () = #krate::__private::assert_parent_variant::<#p_ty, #self_ty>(
+                        #variant_name,
+                        #message,
+                    );
                 }
+            } else {
+                static_check(
+                    input,
+                    p_ty.span(),
+                    quote! {
+                        { let _ = <#p_ty as #krate::__private::ParentOf<#self_ty>>::VARIANTS; }
+                    },
+                )
             });
         }
     }
 
+    // An inferred root is checked once here rather than wherever the impls above name it; those
+    // for a root that can't be inferred never apply.
+    let mut inferred = parents.iter().filter(|parent| parent.root_inferred);
+    if let Some(first) = inferred.next() {
+        let first_ty = &first.parent;
+        let krate = respan(&krate, first_ty.span());
+        parent_checks.push(static_check(
+            input,
+            first_ty.span(),
+            quote_spanned! {first_ty.span()=> #krate::__private::assert_single_root::<#first_ty>() },
+        ));
+        for ParentAttr { parent: p_ty, .. } in inferred {
+            let krate = respan(&krate, p_ty.span());
+            parent_checks.push(static_check(
+                input,
+                p_ty.span(),
+                quote_spanned! {p_ty.span()=> #krate::__private::assert_same_root::<#first_ty, #p_ty>() },
+            ));
+        }
+    }
+
+    // A node of a single tree joins every tree that tree's root is mounted in, the root's own
+    // included, with one set of impls generic over the tree. A node of several trees gets a set
+    // for each of them.
+    let single_tree = trees.len() == 1;
+    for tree in &trees {
+        let r_ty = tree.root;
+        let p_ty = tree.parents[0];
+        let root_param = root_param();
+        let mut generics = input.generics.clone();
+        let tree_ty = if single_tree {
+            generics.params.push(syn::parse_quote!(#root_param));
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#r_ty: #krate::__private::MountedIn<#root_param>));
+            quote!(#root_param)
+        } else {
+            quote!(#r_ty)
+        };
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        // Parents of an inferred root already have `ChildOf` in all of their trees.
+        let child_of_p_tys = if malformed || tree.root_inferred {
+            &[][..]
+        } else {
+            &tree.parents[..]
+        };
+        enum_impls.push(quote! {
+            impl #impl_generics #krate::EnumTree<#tree_ty> for #self_ty #where_clause { type P = #p_ty; }
+            impl #impl_generics #marker<#tree_ty> for #self_ty #where_clause {}
+            #(
+                impl #impl_generics #krate::__private::ChildOf<#child_of_p_tys, #tree_ty> for #self_ty #where_clause {}
+            )*
+        });
+
+        // An inferred root may not be a tree; see `assert_single_root` above.
+        let mut path_generics = generics.clone();
+        let path_where_clause = path_generics.make_where_clause();
+        if single_tree {
+            path_where_clause
+                .predicates
+                .push(syn::parse_quote!(#p_ty: #krate::EnumTreeNodePath<#tree_ty>));
+        } else if tree.root_inferred {
+            path_where_clause.predicates.push(syn::parse_quote! {
+                for<'__enum_tree> #p_ty: #krate::EnumTreeNodePath<#tree_ty>
+            });
+        }
+        if generic {
+            path_where_clause
+                .predicates
+                .push(syn::parse_quote!(#p_ty: #krate::__private::ParentOf<#self_ty>));
+        }
+        let (_, _, path_where_clause) = path_generics.split_for_impl();
+        let path_variant = &tree.path_variant;
+        enum_impls.push(quote! {
+            impl #impl_generics #krate::EnumTreeNodePath<#tree_ty> for #self_ty #path_where_clause {
+                const PATH: &'static #krate::NodePath = &#krate::NodePath::child(<#p_ty as #krate::EnumTreeNodePath<#tree_ty>>::PATH, #path_variant);
+            }
+        });
+
+        // The root's children are extracted through the root's `ChildFromRoot` impls rather than
+        // its `TryFrom` impls, which would be this node's own `TryFrom<R>`. Its bound holds once a
+        // parent wraps this node; under `for<'a>` an unmet bound only disables the impl, so a
+        // parent lacking the variant still gets the single error from the check above.
+        enum_impls.push(expand_ancestry(
+            &krate,
+            &generics,
+            &self_ty,
+            &tree_ty,
+            &tree.parents,
+            ParentStep::ChildFromRoot,
+            &tree.variant_checks,
+        ));
+        // `TryFrom` is only implemented for the root itself: an impl for any tree would overlap
+        // the blanket `TryFrom` impl of `core`.
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! {
+                for<'__enum_tree> #self_ty: #krate::TryFromEnumTreeRoot<#r_ty>
+            });
+        let (impl_generics, _, try_where_clause) = generics.split_for_impl();
+        enum_impls.push(quote! {
+            impl #impl_generics ::core::convert::TryFrom<#r_ty> for #self_ty #try_where_clause {
+                type Error = #krate::Mismatch<#r_ty>;
+                fn try_from(root: #r_ty) -> ::core::result::Result<Self, Self::Error> {
+                    #krate::TryFromEnumTreeRoot::<#r_ty>::try_from_root(root)
+                }
+            }
+        });
+    }
+
     quote! {
         #(#enum_impls)*
-        #(#from_impls)*
+        #(#parent_checks)*
+    }
+}
+
+/// The parents a node declares in one tree, in declaration order, with what its impls for that
+/// tree are built from.
+struct TreeParents<'a> {
+    /// The root as written, which tells the trees apart.
+    key: String,
+    root: &'a Type,
+    root_inferred: bool,
+    parents: Vec<&'a Type>,
+    /// The variant of the first parent wrapping the node, for its `EnumTreeNodePath::PATH`.
+    path_variant: proc_macro2::TokenStream,
+    /// The variant checks of a generic node, run by its conversions from the root.
+    variant_checks: proc_macro2::TokenStream,
+}
+
+/// Emits a compile-time check at `span`. Checks on non-generic enums are evaluated in a constant;
+/// on generic enums, which constants can't name, they are only type-checked inside a function
+/// that is never called.
+fn static_check(
+    input: &DeriveInput,
+    span: proc_macro2::Span,
+    check: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if input.generics.params.is_empty() {
+        return quote_spanned! {span=> const _: () = #check; };
     }
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+    quote_spanned! {span=>
+        const _: () = {
+            #[allow(dead_code)]
+            fn check #impl_generics () #where_clause { #check }
+        };
+    }
+}
+
+/// `tokens` with every token at `span`, for a generated call whose errors should point there.
+fn respan(tokens: &proc_macro2::TokenStream, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+    tokens
+        .clone()
+        .into_iter()
+        .map(|mut tt| {
+            tt.set_span(span);
+            tt
+        })
+        .collect()
 }
 
-fn parse_two_type_args(attr: &Attribute) -> (Type, Type) {
-    // Expect attribute like #[enum_tree_inner(P, R)] or #[enum_tree_leaf(P, R)]
-    use syn::{Token, punctuated::Punctuated};
-    let args: Punctuated<Type, Token![,]> = attr
-        .parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)
-        .expect("failed to parse attribute type arguments");
-    let mut args_iter = args.into_iter();
-    let p = args_iter
-        .next()
-        .expect("expected parent type as first attribute argument");
-    let r = args_iter
-        .next()
-        .expect("expected root type as second attribute argument");
-    (p, r)
+/// Renders a type the way a user would write it, for use in error messages.
+fn type_display(ty: &impl quote::ToTokens) -> String {
+    quote!(#ty)
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
 }
 
-fn type_key(ty: &Type) -> String {
-    if let Type::Path(type_path) = ty {
-        if let Some(seg) = type_path.path.segments.last() {
-            return quote!(#seg).to_string();
+/// Collects every problem found while expanding, so that they are reported together alongside
+/// whatever impls could still be generated.
+#[derive(Default)]
+pub(crate) struct Errors(Option<syn::Error>);
+
+impl Errors {
+    pub(crate) fn push(&mut self, error: syn::Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
         }
     }
-    quote!(#ty).to_string()
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    pub(crate) fn into_compile_error(self) -> proc_macro2::TokenStream {
+        self.0
+            .map(|errors| errors.to_compile_error())
+            .unwrap_or_default()
+    }
 }
@@ -1,7 +1,19 @@
+mod test_expand_attr_errors;
+mod test_expand_context_slots;
+mod test_expand_crate_path;
+mod test_expand_generics;
 mod test_expand_inner_multiple_roots;
 mod test_expand_leaf_multiple_roots;
 mod test_expand_leaf_tuple_variants;
 mod test_expand_leaf_under_root;
+mod test_expand_multiple_positions;
 mod test_expand_nested_enum_inner;
 mod test_expand_nested_enum_leaf;
 mod test_expand_nested_enum_root;
+mod test_expand_pointer_slots;
+mod test_expand_root_inner;
+mod test_expand_struct_leaf;
+mod test_expand_top_down;
+mod test_expand_tree_macro;
+mod test_expand_unified_attr;
+mod test_expand_variant_rename;
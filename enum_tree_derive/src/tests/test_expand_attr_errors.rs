@@ -0,0 +1,213 @@
+use syn::parse_quote;
+
+use crate::{expand_enum_tree, expand_enum_tree_inner, expand_enum_tree_leaf};
+
+fn compile_errors(tokens: &proc_macro2::TokenStream) -> usize {
+    tokens.to_string().matches("compile_error").count()
+}
+
+#[test]
+fn malformed_parent_attributes_do_not_panic() {
+    let inputs: Vec<syn::DeriveInput> = vec![
+        parse_quote! {
+            #[enum_tree_leaf(MenuFlow)]
+            pub enum General { ClickBack }
+        },
+        parse_quote! {
+            #[enum_tree_leaf()]
+            pub enum General { ClickBack }
+        },
+        parse_quote! {
+            #[enum_tree_leaf(MenuFlow, RootAction, Extra)]
+            pub enum General { ClickBack }
+        },
+        parse_quote! {
+            #[enum_tree_leaf(MenuFlow, RootAction, variant = General, Extra)]
+            pub enum General { ClickBack }
+        },
+    ];
+
+    for input in inputs {
+        let tokens = std::panic::catch_unwind(|| expand_enum_tree_leaf(input))
+            .expect("malformed attribute arguments must not panic");
+        assert_eq!(compile_errors(&tokens), 1, "{tokens}");
+    }
+}
+
+#[test]
+fn valid_attributes_still_expand_next_to_errors() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(ParentOne, RootOne)]
+        #[enum_tree_inner(ParentTwo)]
+        pub enum Child {
+            Leaf(Leaf),
+        }
+    };
+
+    let tokens = expand_enum_tree_inner(input);
+    let actual = tokens.to_string();
+    assert_eq!(compile_errors(&tokens), 1, "{actual}");
+    assert!(actual.contains(
+        "impl < __EnumTreeRoot > :: enum_tree :: EnumTree < __EnumTreeRoot > for Child where RootOne"
+    ));
+    assert!(actual.contains("expected root type after the parent type"));
+}
+
+#[test]
+fn every_leaf_tuple_variant_is_reported() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(MenuFlow, RootAction)]
+        pub enum IpSetup {
+            UpdatePortText(String),
+            ClickStartIp,
+            Scroll(f32, f32),
+        }
+    };
+
+    let tokens = expand_enum_tree_leaf(input);
+    let actual = tokens.to_string();
+    assert_eq!(compile_errors(&tokens), 2, "{actual}");
+    assert!(actual.contains("found tuple variant 'UpdatePortText'"));
+    assert!(actual.contains("found tuple variant 'Scroll'"));
+    assert!(actual.contains(
+        "impl < __EnumTreeRoot > :: enum_tree :: EnumTreeLeaf < __EnumTreeRoot > for IpSetup where RootAction"
+    ));
+}
+
+#[test]
+fn conflicting_attributes_are_reported() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_root]
+        #[enum_tree_root]
+        #[enum_tree_leaf(RootAction, RootAction)]
+        pub enum RootAction {
+            MenuFlow(MenuFlow),
+        }
+    };
+
+    let tokens = expand_enum_tree(input);
+    let actual = tokens.to_string();
+    assert_eq!(compile_errors(&tokens), 2, "{actual}");
+    assert!(actual.contains("duplicate `#[enum_tree_root]` attribute"));
+    assert!(actual.contains("`#[enum_tree_leaf]` cannot be combined with `#[enum_tree_root]`"));
+    assert!(actual.contains("impl :: enum_tree :: EnumTreeRoot < RootAction > for RootAction"));
+}
+
+#[test]
+fn unknown_root_argument_is_reported() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_root(bottom_up)]
+        pub enum RootAction {
+            MenuFlow(MenuFlow),
+        }
+    };
+
+    let tokens = expand_enum_tree(input);
+    assert_eq!(compile_errors(&tokens), 1, "{tokens}");
+    assert!(
+        tokens
+            .to_string()
+            .contains("unknown argument, expected `top_down`")
+    );
+}
+
+#[test]
+fn allow_tuple_is_rejected_outside_leaves() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction, allow_tuple)]
+        pub enum MenuFlow {
+            General(General),
+        }
+    };
+
+    let tokens = expand_enum_tree_inner(input);
+    let actual = tokens.to_string();
+    assert_eq!(compile_errors(&tokens), 1, "{actual}");
+    assert!(actual.contains("`allow_tuple` only applies to `#[enum_tree_leaf]`"));
+}
+
+#[test]
+fn malformed_child_markers_are_reported() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction)]
+        pub enum Editor {
+            Buffer { #[enum_tree(child)] id: BufferId, #[enum_tree(child)] action: BufferAction },
+            Split(SplitId, #[enum_tree(parent)] Pane),
+        }
+    };
+
+    let tokens = expand_enum_tree_inner(input);
+    let actual = tokens.to_string();
+    assert_eq!(compile_errors(&tokens), 2, "{actual}");
+    assert!(actual.contains("only one field of a variant can be marked `#[enum_tree(child)]`"));
+    assert!(actual.contains("unknown argument, expected `child`"));
+}
+
+#[test]
+fn malformed_unified_attributes_are_reported() {
+    let cases: Vec<(syn::DeriveInput, &str)> = vec![
+        (
+            parse_quote! {
+                #[enum_tree(parent = MenuFlow)]
+                pub enum General { ClickBack }
+            },
+            "missing `kind = root`, `kind = inner` or `kind = leaf`",
+        ),
+        (
+            parse_quote! {
+                #[enum_tree(kind = leaf)]
+                pub enum General { ClickBack }
+            },
+            "missing `parent = ...` for `kind = leaf`",
+        ),
+        (
+            parse_quote! {
+                #[enum_tree(kind = branch, parent = MenuFlow)]
+                pub enum General { ClickBack }
+            },
+            "expected `kind = root`, `kind = inner` or `kind = leaf`",
+        ),
+        (
+            parse_quote! {
+                #[enum_tree(kind = root, parent = MenuFlow)]
+                pub enum RootAction { MenuFlow(MenuFlow) }
+            },
+            "`parent` does not apply to `kind = root`",
+        ),
+        (
+            parse_quote! {
+                #[enum_tree(kind = leaf, parent = MenuFlow, top_down)]
+                pub enum General { ClickBack }
+            },
+            "`top_down` only applies to `kind = root`",
+        ),
+        (
+            parse_quote! {
+                #[enum_tree(kind = leaf, parent = MenuFlow, bottom_up)]
+                pub enum General { ClickBack }
+            },
+            "unknown argument `bottom_up`",
+        ),
+    ];
+
+    for (input, message) in cases {
+        let tokens = expand_enum_tree(input);
+        let actual = tokens.to_string();
+        assert_eq!(compile_errors(&tokens), 1, "{actual}");
+        assert!(actual.contains(message), "{actual}");
+    }
+}
+
+#[test]
+fn duplicate_crate_paths_are_reported() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree(crate = facade::enum_tree)]
+        #[enum_tree(kind = leaf, parent = MenuFlow, crate = other::enum_tree)]
+        pub enum General { ClickBack }
+    };
+
+    let tokens = expand_enum_tree(input);
+    let actual = tokens.to_string();
+    assert_eq!(compile_errors(&tokens), 1, "{actual}");
+    assert!(actual.contains("duplicate argument `crate`"));
+}
@@ -0,0 +1,206 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::expand_enum_tree_inner;
+
+#[test]
+fn inner_with_context_fields() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction)]
+        pub enum Editor {
+            Buffer { id: BufferId, #[enum_tree(child)] action: BufferAction },
+            Split(SplitId, #[enum_tree(child)] Pane),
+        }
+    };
+
+    let expected = quote! {
+
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for Editor where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = RootAction; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for Editor where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<RootAction, __EnumTreeRoot> for Editor where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for Editor
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "Editor");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for Editor
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Editor> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Editor>,
+            for<'__enum_tree> Editor: ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, RootAction, Self>(&root)?;
+                <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for Editor
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Editor: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <Editor as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for Editor
+        where
+            for<'__enum_tree> Editor: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<RootAction, Editor>(
+            "Editor",
+            "parent `RootAction` has no variant `Editor(Editor)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<BufferAction, Editor, RootAction>();
+
+        impl ::core::convert::TryFrom<Editor> for BufferAction {
+            type Error = ::enum_tree::Mismatch<Editor>;
+            fn try_from(value: Editor) -> ::core::result::Result<Self, Self::Error> {
+                if let Editor::Buffer { action: v, .. } = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Editor as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Buffer", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<BufferAction> for Editor {
+            const VARIANTS: &'static [&'static str] = &["Buffer"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Editor> for BufferAction {
+            type Context = (BufferId,);
+            fn try_from_parent_with_context(parent: Editor) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Editor::Buffer { id: c0, action: v } = parent { ::core::option::Option::Some((v, (c0,))) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Editor> for BufferAction {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Editor) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Editor>> {
+                if let Editor::Buffer { action: v, .. } = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Editor as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Buffer", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Editor) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Editor>> {
+                if let Editor::Buffer { action: v, .. } = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Editor as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Buffer", found))
+                }
+            }
+        }
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Pane, Editor, RootAction>();
+
+        impl ::core::convert::TryFrom<Editor> for Pane {
+            type Error = ::enum_tree::Mismatch<Editor>;
+            fn try_from(value: Editor) -> ::core::result::Result<Self, Self::Error> {
+                if let Editor::Split(_, v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Editor as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Split", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Pane> for Editor {
+            const VARIANTS: &'static [&'static str] = &["Split"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Editor> for Pane {
+            type Context = (SplitId,);
+            fn try_from_parent_with_context(parent: Editor) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Editor::Split(c0, v) = parent { ::core::option::Option::Some((v, (c0,))) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Editor> for Pane {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Editor) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Editor>> {
+                if let Editor::Split(_, v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Editor as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Split", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Editor) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Editor>> {
+                if let Editor::Split(_, v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Editor as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Split", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for Editor {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    Editor::Buffer { .. } => "Buffer",
+                    Editor::Split { .. } => "Split",
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot, __EnumTreeChild> ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, __EnumTreeChild> for Editor
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            __EnumTreeChild: ::core::convert::TryFrom<Editor, Error = ::enum_tree::Mismatch<Editor> > + ::enum_tree::TryFromParentRef<Editor>,
+            for<'__enum_tree> Editor: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>
+        {
+            fn child_from_root(root: __EnumTreeRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, Editor, __EnumTreeChild>(root)
+            }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for Editor {
+            type Root = RootAction;
+        }
+    };
+
+    let actual = expand_enum_tree_inner(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
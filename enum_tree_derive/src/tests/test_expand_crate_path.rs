@@ -0,0 +1,42 @@
+use pretty_assertions::assert_eq;
+use syn::parse_quote;
+
+use crate::expand_enum_tree;
+
+#[test]
+fn crate_path_replaces_enum_tree_paths() {
+    let renamed: syn::DeriveInput = parse_quote! {
+        #[enum_tree(crate = facade::enum_tree)]
+        #[enum_tree_inner(RootAction, RootAction)]
+        pub enum MenuFlow { General(General) }
+    };
+    let default: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction)]
+        pub enum MenuFlow { General(General) }
+    };
+
+    let expected = expand_enum_tree(default)
+        .to_string()
+        .replace(":: enum_tree ::", "facade :: enum_tree ::");
+    assert_eq!(expand_enum_tree(renamed).to_string(), expected);
+}
+
+#[test]
+fn crate_path_in_unified_attr() {
+    let unified: syn::DeriveInput = parse_quote! {
+        #[enum_tree(kind = leaf, parent = MenuFlow, crate = facade::enum_tree)]
+        pub enum General { ClickBack }
+    };
+    let positional: syn::DeriveInput = parse_quote! {
+        #[enum_tree(crate = facade::enum_tree)]
+        #[enum_tree_leaf(MenuFlow, _)]
+        pub enum General { ClickBack }
+    };
+
+    let actual = expand_enum_tree(unified).to_string();
+    assert_eq!(actual, expand_enum_tree(positional).to_string());
+    assert_eq!(
+        actual.matches(":: enum_tree ::").count(),
+        actual.matches("facade :: enum_tree ::").count()
+    );
+}
@@ -0,0 +1,228 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{expand_enum_tree_leaf, expand_enum_tree_root};
+
+#[test]
+fn root_with_generics() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_root]
+        pub enum App<T: Id> {
+            Menu(Menu<T>),
+        }
+    };
+
+    let expected = quote! {
+        impl<T: Id> ::enum_tree::EnumTree<App<T> > for App<T> { type P = (); }
+        impl<T: Id> ::enum_tree::EnumTreeRoot<App<T> > for App<T> {}
+        impl<T: Id> ::enum_tree::EnumTreeNodePath<App<T> > for App<T> {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::ROOT;
+        }
+
+        impl<T: Id> ::enum_tree::ToEnumTreeRoot<App<T> > for App<T> {
+            fn to_root(self) -> App<T> { self }
+        }
+
+        impl<T: Id> ::enum_tree::__private::Ancestry<App<T> > for App<T> {
+            type Depth = ::enum_tree::__private::Zero;
+            fn from_parents(root: App<T>) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath), ::enum_tree::Mismatch<App<T> >> {
+                ::core::result::Result::Ok((root, &::enum_tree::NodePath::ROOT))
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree App<T>) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree App<T> >> {
+                ::core::result::Result::Ok(root)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut App<T>) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::core::option::Option::Some(root)
+            }
+            fn from_parents_at(_: App<T>) -> ::core::option::Option<(Self, &'static str)> {
+                ::core::option::Option::None
+            }
+        }
+
+        impl<T: Id> ::enum_tree::__private::MountedIn<App<T> > for App<T> {}
+
+        impl<T: Id> ::enum_tree::TryFromEnumTreeRootWithContext<App<T> > for App<T> {
+            type Context = ();
+            fn from_root_with_context(root: App<T>) -> ::core::option::Option<(Self, ())> { ::core::option::Option::Some((root, ())) }
+        }
+
+        impl<T: Id> ::enum_tree::__private::SingleRoot for App<T> {
+            type Root = App<T>;
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn check<T: Id>() { ::enum_tree::__private::assert_child_of::<Menu<T>, App<T>, App<T> >() }
+        };
+
+        impl<T: Id> ::core::convert::From<Menu<T> > for App<T> {
+            fn from(value: Menu<T>) -> Self { Self::Menu(value) }
+        }
+
+        impl<T: Id> ::enum_tree::__private::ChildFromRoot<App<T>, Menu<T> > for App<T> {
+            fn child_from_root(value: App<T>) -> ::core::result::Result<Menu<T>, ::enum_tree::Mismatch<App<T> >> {
+                if let App::Menu(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <App<T> as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Menu", found))
+                }
+            }
+        }
+
+        impl<T: Id> ::enum_tree::__private::ParentOf<Menu<T> > for App<T> {
+            const VARIANTS: &'static [&'static str] = &["Menu"];
+        }
+
+        impl<T: Id> ::enum_tree::TryFromParentWithContext<App<T> > for Menu<T> {
+            type Context = ();
+            fn try_from_parent_with_context(parent: App<T>) -> ::core::option::Option<(Self, Self::Context)> {
+                if let App::Menu(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl<T: Id> ::enum_tree::TryFromParentRef<App<T> > for Menu<T> {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree App<T> ) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree App<T> >> {
+                if let App::Menu(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <App<T>  as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Menu", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut App<T> ) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut App<T> >> {
+                if let App::Menu(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <App<T>  as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Menu", found))
+                }
+            }
+        }
+
+        impl<T: Id> ::enum_tree::__private::VariantName for App<T>  {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    App::Menu { .. } => "Menu",
+                }
+            }
+        }
+    };
+
+    let actual = expand_enum_tree_root(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn leaf_with_lifetime_and_where_clause() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(Menu<'a, T>, App<'a, T>)]
+        pub enum Payload<'a, T> where T: Id {
+            Text { text: &'a str },
+            Id { id: T },
+        }
+    };
+
+    let expected = quote! {
+
+
+        impl<'a, T, __EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for Payload<'a, T> where T: Id, App<'a, T>: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = Menu<'a, T>; }
+        impl<'a, T, __EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for Payload<'a, T> where T: Id, App<'a, T>: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<'a, T, __EnumTreeRoot> ::enum_tree::__private::ChildOf<Menu<'a, T>, __EnumTreeRoot> for Payload<'a, T> where T: Id, App<'a, T>: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<'a, T, __EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for Payload<'a, T>
+        where
+            T: Id,
+            App<'a, T>: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            Menu<'a, T>: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>,
+            Menu<'a, T>: ::enum_tree::__private::ParentOf<Payload<'a, T> >
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<Menu<'a, T> as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, ::enum_tree::__private::parent_variant::<Menu<'a, T>, Payload<'a, T> >("Payload", "parent `Menu<'a, T>` has no variant `Payload(Payload<'a, T>)` required by `#[enum_tree_leaf]`"));
+        }
+
+        impl<'a, T, __EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for Payload<'a, T>
+        where
+            T: Id,
+            App<'a, T>: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> Menu<'a, T>: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Payload<'a, T> > + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Menu<'a, T>: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Payload<'a, T> >,
+            for<'__enum_tree> Payload<'a, T>: ::enum_tree::TryFromParentRef<Menu<'a, T> >,
+            for<'__enum_tree> Menu<'a, T>: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << Menu<'a, T> as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                const {
+                    ::enum_tree::__private::assert_parent_variant::<Menu<'a, T>,
+                    Payload<'a, T> >("Payload", "parent `Menu<'a, T>` has no variant `Payload(Payload<'a, T>)` required by `#[enum_tree_leaf]`")
+                };
+                let mismatch = match <Menu<'a, T> as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <Menu<'a, T> as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                const {
+                    ::enum_tree::__private::assert_parent_variant::<Menu<'a, T>, Payload<'a, T> >("Payload", "parent `Menu<'a, T>` has no variant `Payload(Payload<'a, T>)` required by `#[enum_tree_leaf]`")
+                };
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, Menu<'a, T>, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                const {
+                    ::enum_tree::__private::assert_parent_variant::<Menu<'a, T>, Payload<'a, T> >("Payload", "parent `Menu<'a, T>` has no variant `Payload(Payload<'a, T>)` required by `#[enum_tree_leaf]`")
+                };
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, Menu<'a, T>, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                const {
+                    ::enum_tree::__private::assert_parent_variant::<Menu<'a, T>, Payload<'a, T> >("Payload", "parent `Menu<'a, T>` has no variant `Payload(Payload<'a, T>)` required by `#[enum_tree_leaf]`")
+                };
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, Menu<'a, T>, Self>(&root)?;
+                <Menu<'a, T> as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<'a, T, __EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for Payload<'a, T>
+        where
+            T: Id,
+            App<'a, T>: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> Menu<'a, T>: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Payload<'a, T>: ::enum_tree::TryFromParentWithContext<Menu<'a, T> > + ::enum_tree::TryFromParentRef<Menu<'a, T> >
+        {
+            type Context = (<Menu<'a, T> as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <Payload<'a, T> as ::enum_tree::TryFromParentWithContext<Menu<'a, T> >>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                const {
+                    ::enum_tree::__private::assert_parent_variant::<Menu<'a, T>, Payload<'a, T> >("Payload", "parent `Menu<'a, T>` has no variant `Payload(Payload<'a, T>)` required by `#[enum_tree_leaf]`")
+                };
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, Menu<'a, T>, Self>(root)
+            }
+        }
+
+        impl<'a, T> ::core::convert::TryFrom<App<'a, T> > for Payload<'a, T>
+        where
+            T: Id,
+            for<'__enum_tree> Payload<'a, T>: ::enum_tree::TryFromEnumTreeRoot<App<'a, T> >
+        {
+            type Error = ::enum_tree::Mismatch<App<'a, T> >;
+            fn try_from(root: App<'a, T>) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<App<'a, T> >::try_from_root(root)
+            }
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn check<'a, T>() where T: Id {
+                { let _ = <Menu<'a, T> as ::enum_tree::__private::ParentOf<Payload<'a, T> >>::VARIANTS; }
+            }
+        };
+    };
+
+    let actual = expand_enum_tree_leaf(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
@@ -17,24 +17,215 @@ fn inner_multiple_roots_different_parents() {
     let expected = quote! {
         impl ::enum_tree::EnumTree<RootOne> for Child { type P = ParentOne; }
         impl ::enum_tree::EnumTreeInner<RootOne> for Child {}
+
+        impl ::enum_tree::__private::ChildOf<ParentOne, RootOne> for Child {}
+        impl ::enum_tree::EnumTreeNodePath<RootOne> for Child {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<ParentOne as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH, "Child");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootOne> for Child
+        where
+            for<'__enum_tree> ParentOne: ::enum_tree::__private::ChildFromRoot<RootOne, Child> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> ParentOne: ::enum_tree::EnumTreeNodePath<RootOne> + ::enum_tree::__private::ParentOf<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentRef<ParentOne>,
+            for<'__enum_tree> ParentOne: ::enum_tree::__private::Ancestry<RootOne>
+        {
+            type Depth = ::enum_tree::__private::Succ << ParentOne as ::enum_tree::__private::Ancestry<RootOne>>::Depth>;
+            fn from_parents(root: RootOne) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootOne>> {
+                let mismatch = match <ParentOne as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <ParentOne as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootOne) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootOne>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootOne, ParentOne, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootOne) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootOne, ParentOne, Self>(root)
+            }
+            fn from_parents_at(root: RootOne) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootOne, ParentOne, Self>(&root)?;
+                <ParentOne as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootOne> for Child
+        where
+            for<'__enum_tree> ParentOne: ::enum_tree::TryFromEnumTreeRootWithContext<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentWithContext<ParentOne> + ::enum_tree::TryFromParentRef<ParentOne>
+        {
+            type Context = (<ParentOne as ::enum_tree::TryFromEnumTreeRootWithContext<RootOne>>::Context, <Child as ::enum_tree::TryFromParentWithContext<ParentOne>>::Context);
+            fn from_parents_with_context(root: RootOne) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootOne, ParentOne, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootOne> for Child
+        where
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootOne>
+        {
+            type Error = ::enum_tree::Mismatch<RootOne>;
+            fn try_from(root: RootOne) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootOne>::try_from_root(root)
+            }
+        }
         impl ::enum_tree::EnumTree<RootTwo> for Child { type P = ParentTwo; }
         impl ::enum_tree::EnumTreeInner<RootTwo> for Child {}
-        impl From<Child> for ParentOne {
-            fn from(value: Child) -> Self { ParentOne::Child(value) }
+
+        impl ::enum_tree::__private::ChildOf<ParentTwo, RootTwo> for Child {}
+        impl ::enum_tree::EnumTreeNodePath<RootTwo> for Child {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<ParentTwo as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH, "Child");
         }
-        impl TryFrom<ParentOne> for Child {
-            type Error = (); fn try_from(value: ParentOne) -> Result<Self, Self::Error> {
-                if let ParentOne::Child(v) = value { Ok(v) } else { Err(()) }
+
+        impl ::enum_tree::__private::Ancestry<RootTwo> for Child
+        where
+            for<'__enum_tree> ParentTwo: ::enum_tree::__private::ChildFromRoot<RootTwo, Child> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> ParentTwo: ::enum_tree::EnumTreeNodePath<RootTwo> + ::enum_tree::__private::ParentOf<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentRef<ParentTwo>,
+            for<'__enum_tree> ParentTwo: ::enum_tree::__private::Ancestry<RootTwo>
+        {
+            type Depth = ::enum_tree::__private::Succ << ParentTwo as ::enum_tree::__private::Ancestry<RootTwo>>::Depth>;
+            fn from_parents(root: RootTwo) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootTwo>> {
+                let mismatch = match <ParentTwo as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <ParentTwo as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootTwo) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootTwo>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootTwo, ParentTwo, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootTwo) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootTwo, ParentTwo, Self>(root)
+            }
+            fn from_parents_at(root: RootTwo) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootTwo, ParentTwo, Self>(&root)?;
+                <ParentTwo as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root).ok().map(|node| (node, position))
             }
         }
-        impl From<Child> for ParentTwo {
-            fn from(value: Child) -> Self { ParentTwo::Child(value) }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootTwo> for Child
+        where
+            for<'__enum_tree> ParentTwo: ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentWithContext<ParentTwo> + ::enum_tree::TryFromParentRef<ParentTwo>
+        {
+            type Context = (<ParentTwo as ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo>>::Context, <Child as ::enum_tree::TryFromParentWithContext<ParentTwo>>::Context);
+            fn from_parents_with_context(root: RootTwo) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootTwo, ParentTwo, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootTwo> for Child
+        where
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootTwo>
+        {
+            type Error = ::enum_tree::Mismatch<RootTwo>;
+            fn try_from(root: RootTwo) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootTwo>::try_from_root(root)
+            }
+        }
+        const _: () = ::enum_tree::__private::assert_parent_variant::<ParentOne, Child>(
+            "Child",
+            "parent `ParentOne` has no variant `Child(Child)` required by `#[enum_tree_inner]`",
+        );
+        const _: () = ::enum_tree::__private::assert_parent_variant::<ParentTwo, Child>(
+            "Child",
+            "parent `ParentTwo` has no variant `Child(Child)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Leaf, Child, RootOne>();
+        const _: () = ::enum_tree::__private::assert_child_of::<Leaf, Child, RootTwo>();
+
+        impl ::core::convert::From<Leaf> for Child {
+            fn from(value: Leaf) -> Self { Self::Leaf(value) }
         }
-        impl TryFrom<ParentTwo> for Child {
-            type Error = (); fn try_from(value: ParentTwo) -> Result<Self, Self::Error> {
-                if let ParentTwo::Child(v) = value { Ok(v) } else { Err(()) }
+
+        impl ::core::convert::TryFrom<Child> for Leaf {
+            type Error = ::enum_tree::Mismatch<Child>;
+            fn try_from(value: Child) -> ::core::result::Result<Self, Self::Error> {
+                if let Child::Leaf(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Leaf", found))
+                }
             }
         }
+
+        impl ::enum_tree::__private::ParentOf<Leaf> for Child {
+            const VARIANTS: &'static [&'static str] = &["Leaf"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Child> for Leaf {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Child) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Child::Leaf(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Child> for Leaf {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Child) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Child>> {
+                if let Child::Leaf(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Leaf", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Child) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Child>> {
+                if let Child::Leaf(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Leaf", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for Child {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    Child::Leaf { .. } => "Leaf",
+                }
+            }
+        }
+
+        impl<__EnumTreeChild> ::enum_tree::__private::ChildFromRoot<RootOne, __EnumTreeChild> for Child
+        where
+            __EnumTreeChild: ::core::convert::TryFrom<Child, Error = ::enum_tree::Mismatch<Child> > + ::enum_tree::TryFromParentRef<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>
+        {
+            fn child_from_root(root: RootOne) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<RootOne>> {
+                ::enum_tree::__private::child_from_root::<RootOne, Child, __EnumTreeChild>(root)
+            }
+        }
+
+        impl<__EnumTreeChild> ::enum_tree::__private::ChildFromRoot<RootTwo, __EnumTreeChild> for Child
+        where
+            __EnumTreeChild: ::core::convert::TryFrom<Child, Error = ::enum_tree::Mismatch<Child> > + ::enum_tree::TryFromParentRef<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>
+        {
+            fn child_from_root(root: RootTwo) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<RootTwo>> {
+                ::enum_tree::__private::child_from_root::<RootTwo, Child, __EnumTreeChild>(root)
+            }
+        }
+
+        const _: () = {
+            pub struct NoSingleRoot<T: ?Sized>(::core::marker::PhantomData<T>);
+
+            impl ::enum_tree::__private::SingleRoot for Child {
+                type Root = NoSingleRoot<Self>;
+            }
+        };
     };
 
     let actual = expand_enum_tree_inner(input);
@@ -54,16 +245,211 @@ fn inner_multiple_roots_same_parent() {
     let expected = quote! {
         impl ::enum_tree::EnumTree<RootOne> for Child { type P = Parent; }
         impl ::enum_tree::EnumTreeInner<RootOne> for Child {}
+
+        impl ::enum_tree::__private::ChildOf<Parent, RootOne> for Child {}
+        impl ::enum_tree::EnumTreeNodePath<RootOne> for Child {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<Parent as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH, "Child");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootOne> for Child
+        where
+            for<'__enum_tree> Parent: ::enum_tree::__private::ChildFromRoot<RootOne, Child> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Parent: ::enum_tree::EnumTreeNodePath<RootOne> + ::enum_tree::__private::ParentOf<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentRef<Parent>,
+            for<'__enum_tree> Parent: ::enum_tree::__private::Ancestry<RootOne>
+        {
+            type Depth = ::enum_tree::__private::Succ << Parent as ::enum_tree::__private::Ancestry<RootOne>>::Depth>;
+            fn from_parents(root: RootOne) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootOne>> {
+                let mismatch = match <Parent as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <Parent as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootOne) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootOne>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootOne, Parent, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootOne) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootOne, Parent, Self>(root)
+            }
+            fn from_parents_at(root: RootOne) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootOne, Parent, Self>(&root)?;
+                <Parent as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootOne> for Child
+        where
+            for<'__enum_tree> Parent: ::enum_tree::TryFromEnumTreeRootWithContext<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentWithContext<Parent> + ::enum_tree::TryFromParentRef<Parent>
+        {
+            type Context = (<Parent as ::enum_tree::TryFromEnumTreeRootWithContext<RootOne>>::Context, <Child as ::enum_tree::TryFromParentWithContext<Parent>>::Context);
+            fn from_parents_with_context(root: RootOne) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootOne, Parent, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootOne> for Child
+        where
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootOne>
+        {
+            type Error = ::enum_tree::Mismatch<RootOne>;
+            fn try_from(root: RootOne) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootOne>::try_from_root(root)
+            }
+        }
         impl ::enum_tree::EnumTree<RootTwo> for Child { type P = Parent; }
         impl ::enum_tree::EnumTreeInner<RootTwo> for Child {}
-        impl From<Child> for Parent {
-            fn from(value: Child) -> Self { Parent::Child(value) }
+
+        impl ::enum_tree::__private::ChildOf<Parent, RootTwo> for Child {}
+        impl ::enum_tree::EnumTreeNodePath<RootTwo> for Child {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<Parent as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH, "Child");
         }
-        impl TryFrom<Parent> for Child {
-            type Error = (); fn try_from(value: Parent) -> Result<Self, Self::Error> {
-                if let Parent::Child(v) = value { Ok(v) } else { Err(()) }
+
+        impl ::enum_tree::__private::Ancestry<RootTwo> for Child
+        where
+            for<'__enum_tree> Parent: ::enum_tree::__private::ChildFromRoot<RootTwo, Child> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Parent: ::enum_tree::EnumTreeNodePath<RootTwo> + ::enum_tree::__private::ParentOf<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentRef<Parent>,
+            for<'__enum_tree> Parent: ::enum_tree::__private::Ancestry<RootTwo>
+        {
+            type Depth = ::enum_tree::__private::Succ << Parent as ::enum_tree::__private::Ancestry<RootTwo>>::Depth>;
+            fn from_parents(root: RootTwo) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootTwo>> {
+                let mismatch = match <Parent as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <Parent as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootTwo) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootTwo>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootTwo, Parent, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootTwo) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootTwo, Parent, Self>(root)
+            }
+            fn from_parents_at(root: RootTwo) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootTwo, Parent, Self>(&root)?;
+                <Parent as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root).ok().map(|node| (node, position))
             }
         }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootTwo> for Child
+        where
+            for<'__enum_tree> Parent: ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentWithContext<Parent> + ::enum_tree::TryFromParentRef<Parent>
+        {
+            type Context = (<Parent as ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo>>::Context, <Child as ::enum_tree::TryFromParentWithContext<Parent>>::Context);
+            fn from_parents_with_context(root: RootTwo) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootTwo, Parent, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootTwo> for Child
+        where
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootTwo>
+        {
+            type Error = ::enum_tree::Mismatch<RootTwo>;
+            fn try_from(root: RootTwo) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootTwo>::try_from_root(root)
+            }
+        }
+        const _: () = ::enum_tree::__private::assert_parent_variant::<Parent, Child>(
+            "Child",
+            "parent `Parent` has no variant `Child(Child)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Leaf, Child, RootOne>();
+        const _: () = ::enum_tree::__private::assert_child_of::<Leaf, Child, RootTwo>();
+
+        impl ::core::convert::From<Leaf> for Child {
+            fn from(value: Leaf) -> Self { Self::Leaf(value) }
+        }
+
+        impl ::core::convert::TryFrom<Child> for Leaf {
+            type Error = ::enum_tree::Mismatch<Child>;
+            fn try_from(value: Child) -> ::core::result::Result<Self, Self::Error> {
+                if let Child::Leaf(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Leaf", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Leaf> for Child {
+            const VARIANTS: &'static [&'static str] = &["Leaf"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Child> for Leaf {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Child) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Child::Leaf(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Child> for Leaf {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Child) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Child>> {
+                if let Child::Leaf(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Leaf", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Child) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Child>> {
+                if let Child::Leaf(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Leaf", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for Child {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    Child::Leaf { .. } => "Leaf",
+                }
+            }
+        }
+
+        impl<__EnumTreeChild> ::enum_tree::__private::ChildFromRoot<RootOne, __EnumTreeChild> for Child
+        where
+            __EnumTreeChild: ::core::convert::TryFrom<Child, Error = ::enum_tree::Mismatch<Child> > + ::enum_tree::TryFromParentRef<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>
+        {
+            fn child_from_root(root: RootOne) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<RootOne>> {
+                ::enum_tree::__private::child_from_root::<RootOne, Child, __EnumTreeChild>(root)
+            }
+        }
+
+        impl<__EnumTreeChild> ::enum_tree::__private::ChildFromRoot<RootTwo, __EnumTreeChild> for Child
+        where
+            __EnumTreeChild: ::core::convert::TryFrom<Child, Error = ::enum_tree::Mismatch<Child> > + ::enum_tree::TryFromParentRef<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>
+        {
+            fn child_from_root(root: RootTwo) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<RootTwo>> {
+                ::enum_tree::__private::child_from_root::<RootTwo, Child, __EnumTreeChild>(root)
+            }
+        }
+
+        const _: () = {
+            pub struct NoSingleRoot<T: ?Sized>(::core::marker::PhantomData<T>);
+
+            impl ::enum_tree::__private::SingleRoot for Child {
+                type Root = NoSingleRoot<Self>;
+            }
+        };
     };
 
     let actual = expand_enum_tree_inner(input);
@@ -83,16 +469,215 @@ fn inner_multiple_roots_same_parent_alias() {
     let expected = quote! {
         impl ::enum_tree::EnumTree<RootOne> for Child { type P = super::Parent; }
         impl ::enum_tree::EnumTreeInner<RootOne> for Child {}
+
+        impl ::enum_tree::__private::ChildOf<super::Parent, RootOne> for Child {}
+        impl ::enum_tree::EnumTreeNodePath<RootOne> for Child {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<super::Parent as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH, "Child");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootOne> for Child
+        where
+            for<'__enum_tree> super::Parent: ::enum_tree::__private::ChildFromRoot<RootOne, Child> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> super::Parent: ::enum_tree::EnumTreeNodePath<RootOne> + ::enum_tree::__private::ParentOf<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentRef<super::Parent>,
+            for<'__enum_tree> super::Parent: ::enum_tree::__private::Ancestry<RootOne>
+        {
+            type Depth = ::enum_tree::__private::Succ << super::Parent as ::enum_tree::__private::Ancestry<RootOne>>::Depth>;
+            fn from_parents(root: RootOne) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootOne>> {
+                let mismatch = match <super::Parent as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <super::Parent as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootOne) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootOne>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootOne, super::Parent, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootOne) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootOne, super::Parent, Self>(root)
+            }
+            fn from_parents_at(root: RootOne) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootOne, super::Parent, Self>(&root)?;
+                <super::Parent as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootOne> for Child
+        where
+            for<'__enum_tree> super::Parent: ::enum_tree::TryFromEnumTreeRootWithContext<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentWithContext<super::Parent> + ::enum_tree::TryFromParentRef<super::Parent>
+        {
+            type Context = (<super::Parent as ::enum_tree::TryFromEnumTreeRootWithContext<RootOne>>::Context, <Child as ::enum_tree::TryFromParentWithContext<super::Parent>>::Context);
+            fn from_parents_with_context(root: RootOne) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootOne, super::Parent, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootOne> for Child
+        where
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootOne>
+        {
+            type Error = ::enum_tree::Mismatch<RootOne>;
+            fn try_from(root: RootOne) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootOne>::try_from_root(root)
+            }
+        }
         impl ::enum_tree::EnumTree<RootTwo> for Child { type P = crate::mods::Parent; }
         impl ::enum_tree::EnumTreeInner<RootTwo> for Child {}
-        impl From<Child> for super::Parent {
-            fn from(value: Child) -> Self { super::Parent::Child(value) }
+
+        impl ::enum_tree::__private::ChildOf<crate::mods::Parent, RootTwo> for Child {}
+        impl ::enum_tree::EnumTreeNodePath<RootTwo> for Child {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<crate::mods::Parent as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH, "Child");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootTwo> for Child
+        where
+            for<'__enum_tree> crate::mods::Parent: ::enum_tree::__private::ChildFromRoot<RootTwo, Child> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> crate::mods::Parent: ::enum_tree::EnumTreeNodePath<RootTwo> + ::enum_tree::__private::ParentOf<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentRef<crate::mods::Parent>,
+            for<'__enum_tree> crate::mods::Parent: ::enum_tree::__private::Ancestry<RootTwo>
+        {
+            type Depth = ::enum_tree::__private::Succ << crate::mods::Parent as ::enum_tree::__private::Ancestry<RootTwo>>::Depth>;
+            fn from_parents(root: RootTwo) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootTwo>> {
+                let mismatch = match <crate::mods::Parent as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <crate::mods::Parent as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootTwo) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootTwo>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootTwo, crate::mods::Parent, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootTwo) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootTwo, crate::mods::Parent, Self>(root)
+            }
+            fn from_parents_at(root: RootTwo) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootTwo, crate::mods::Parent, Self>(&root)?;
+                <crate::mods::Parent as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootTwo> for Child
+        where
+            for<'__enum_tree> crate::mods::Parent: ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromParentWithContext<crate::mods::Parent> + ::enum_tree::TryFromParentRef<crate::mods::Parent>
+        {
+            type Context = (<crate::mods::Parent as ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo>>::Context, <Child as ::enum_tree::TryFromParentWithContext<crate::mods::Parent>>::Context);
+            fn from_parents_with_context(root: RootTwo) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootTwo, crate::mods::Parent, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootTwo> for Child
+        where
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootTwo>
+        {
+            type Error = ::enum_tree::Mismatch<RootTwo>;
+            fn try_from(root: RootTwo) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootTwo>::try_from_root(root)
+            }
+        }
+        const _: () = ::enum_tree::__private::assert_parent_variant::<super::Parent, Child>(
+            "Child",
+            "parent `super::Parent` has no variant `Child(Child)` required by `#[enum_tree_inner]`",
+        );
+        const _: () = ::enum_tree::__private::assert_parent_variant::<crate::mods::Parent, Child>(
+            "Child",
+            "parent `crate::mods::Parent` has no variant `Child(Child)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Leaf, Child, RootOne>();
+        const _: () = ::enum_tree::__private::assert_child_of::<Leaf, Child, RootTwo>();
+
+        impl ::core::convert::From<Leaf> for Child {
+            fn from(value: Leaf) -> Self { Self::Leaf(value) }
+        }
+
+        impl ::core::convert::TryFrom<Child> for Leaf {
+            type Error = ::enum_tree::Mismatch<Child>;
+            fn try_from(value: Child) -> ::core::result::Result<Self, Self::Error> {
+                if let Child::Leaf(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Leaf", found))
+                }
+            }
         }
-        impl TryFrom<super::Parent> for Child {
-            type Error = (); fn try_from(value: super::Parent) -> Result<Self, Self::Error> {
-                if let super::Parent::Child(v) = value { Ok(v) } else { Err(()) }
+
+        impl ::enum_tree::__private::ParentOf<Leaf> for Child {
+            const VARIANTS: &'static [&'static str] = &["Leaf"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Child> for Leaf {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Child) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Child::Leaf(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Child> for Leaf {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Child) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Child>> {
+                if let Child::Leaf(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Leaf", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Child) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Child>> {
+                if let Child::Leaf(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Child as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Leaf", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for Child {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    Child::Leaf { .. } => "Leaf",
+                }
             }
         }
+
+        impl<__EnumTreeChild> ::enum_tree::__private::ChildFromRoot<RootOne, __EnumTreeChild> for Child
+        where
+            __EnumTreeChild: ::core::convert::TryFrom<Child, Error = ::enum_tree::Mismatch<Child> > + ::enum_tree::TryFromParentRef<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>
+        {
+            fn child_from_root(root: RootOne) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<RootOne>> {
+                ::enum_tree::__private::child_from_root::<RootOne, Child, __EnumTreeChild>(root)
+            }
+        }
+
+        impl<__EnumTreeChild> ::enum_tree::__private::ChildFromRoot<RootTwo, __EnumTreeChild> for Child
+        where
+            __EnumTreeChild: ::core::convert::TryFrom<Child, Error = ::enum_tree::Mismatch<Child> > + ::enum_tree::TryFromParentRef<Child>,
+            for<'__enum_tree> Child: ::enum_tree::TryFromEnumTreeRoot<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>
+        {
+            fn child_from_root(root: RootTwo) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<RootTwo>> {
+                ::enum_tree::__private::child_from_root::<RootTwo, Child, __EnumTreeChild>(root)
+            }
+        }
+
+        const _: () = {
+            pub struct NoSingleRoot<T: ?Sized>(::core::marker::PhantomData<T>);
+
+            impl ::enum_tree::__private::SingleRoot for Child {
+                type Root = NoSingleRoot<Self>;
+            }
+        };
     };
 
     let actual = expand_enum_tree_inner(input);
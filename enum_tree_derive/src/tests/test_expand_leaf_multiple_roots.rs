@@ -15,24 +15,130 @@ fn leaf_multiple_roots_different_parents() {
     let expected = quote! {
         impl ::enum_tree::EnumTree<RootOne> for Leaf { type P = ParentOne; }
         impl ::enum_tree::EnumTreeLeaf<RootOne> for Leaf {}
+
+        impl ::enum_tree::__private::ChildOf<ParentOne, RootOne> for Leaf {}
+        impl ::enum_tree::EnumTreeNodePath<RootOne> for Leaf {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<ParentOne as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH, "Leaf");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootOne> for Leaf
+        where
+            for<'__enum_tree> ParentOne: ::enum_tree::__private::ChildFromRoot<RootOne, Leaf> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> ParentOne: ::enum_tree::EnumTreeNodePath<RootOne> + ::enum_tree::__private::ParentOf<Leaf>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentRef<ParentOne>,
+            for<'__enum_tree> ParentOne: ::enum_tree::__private::Ancestry<RootOne>
+        {
+            type Depth = ::enum_tree::__private::Succ << ParentOne as ::enum_tree::__private::Ancestry<RootOne>>::Depth>;
+            fn from_parents(root: RootOne) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootOne>> {
+                let mismatch = match <ParentOne as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <ParentOne as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootOne) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootOne>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootOne, ParentOne, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootOne) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootOne, ParentOne, Self>(root)
+            }
+            fn from_parents_at(root: RootOne) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootOne, ParentOne, Self>(&root)?;
+                <ParentOne as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootOne> for Leaf
+        where
+            for<'__enum_tree> ParentOne: ::enum_tree::TryFromEnumTreeRootWithContext<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentWithContext<ParentOne> + ::enum_tree::TryFromParentRef<ParentOne>
+        {
+            type Context = (<ParentOne as ::enum_tree::TryFromEnumTreeRootWithContext<RootOne>>::Context, <Leaf as ::enum_tree::TryFromParentWithContext<ParentOne>>::Context);
+            fn from_parents_with_context(root: RootOne) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootOne, ParentOne, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootOne> for Leaf
+        where
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromEnumTreeRoot<RootOne>
+        {
+            type Error = ::enum_tree::Mismatch<RootOne>;
+            fn try_from(root: RootOne) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootOne>::try_from_root(root)
+            }
+        }
         impl ::enum_tree::EnumTree<RootTwo> for Leaf { type P = ParentTwo; }
         impl ::enum_tree::EnumTreeLeaf<RootTwo> for Leaf {}
-        impl From<Leaf> for ParentOne {
-            fn from(value: Leaf) -> Self { ParentOne::Leaf(value) }
+
+        impl ::enum_tree::__private::ChildOf<ParentTwo, RootTwo> for Leaf {}
+        impl ::enum_tree::EnumTreeNodePath<RootTwo> for Leaf {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<ParentTwo as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH, "Leaf");
         }
-        impl TryFrom<ParentOne> for Leaf {
-            type Error = (); fn try_from(value: ParentOne) -> Result<Self, Self::Error> {
-                if let ParentOne::Leaf(v) = value { Ok(v) } else { Err(()) }
+
+        impl ::enum_tree::__private::Ancestry<RootTwo> for Leaf
+        where
+            for<'__enum_tree> ParentTwo: ::enum_tree::__private::ChildFromRoot<RootTwo, Leaf> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> ParentTwo: ::enum_tree::EnumTreeNodePath<RootTwo> + ::enum_tree::__private::ParentOf<Leaf>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentRef<ParentTwo>,
+            for<'__enum_tree> ParentTwo: ::enum_tree::__private::Ancestry<RootTwo>
+        {
+            type Depth = ::enum_tree::__private::Succ << ParentTwo as ::enum_tree::__private::Ancestry<RootTwo>>::Depth>;
+            fn from_parents(root: RootTwo) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootTwo>> {
+                let mismatch = match <ParentTwo as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <ParentTwo as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootTwo) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootTwo>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootTwo, ParentTwo, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootTwo) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootTwo, ParentTwo, Self>(root)
+            }
+            fn from_parents_at(root: RootTwo) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootTwo, ParentTwo, Self>(&root)?;
+                <ParentTwo as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root).ok().map(|node| (node, position))
             }
         }
-        impl From<Leaf> for ParentTwo {
-            fn from(value: Leaf) -> Self { ParentTwo::Leaf(value) }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootTwo> for Leaf
+        where
+            for<'__enum_tree> ParentTwo: ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentWithContext<ParentTwo> + ::enum_tree::TryFromParentRef<ParentTwo>
+        {
+            type Context = (<ParentTwo as ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo>>::Context, <Leaf as ::enum_tree::TryFromParentWithContext<ParentTwo>>::Context);
+            fn from_parents_with_context(root: RootTwo) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootTwo, ParentTwo, Self>(root)
+            }
         }
-        impl TryFrom<ParentTwo> for Leaf {
-            type Error = (); fn try_from(value: ParentTwo) -> Result<Self, Self::Error> {
-                if let ParentTwo::Leaf(v) = value { Ok(v) } else { Err(()) }
+
+        impl ::core::convert::TryFrom<RootTwo> for Leaf
+        where
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromEnumTreeRoot<RootTwo>
+        {
+            type Error = ::enum_tree::Mismatch<RootTwo>;
+            fn try_from(root: RootTwo) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootTwo>::try_from_root(root)
             }
         }
+        const _: () = ::enum_tree::__private::assert_parent_variant::<ParentOne, Leaf>(
+            "Leaf",
+            "parent `ParentOne` has no variant `Leaf(Leaf)` required by `#[enum_tree_leaf]`",
+        );
+        const _: () = ::enum_tree::__private::assert_parent_variant::<ParentTwo, Leaf>(
+            "Leaf",
+            "parent `ParentTwo` has no variant `Leaf(Leaf)` required by `#[enum_tree_leaf]`",
+        );
     };
 
     let actual = expand_enum_tree_leaf(input);
@@ -50,16 +156,126 @@ fn leaf_multiple_roots_same_parent() {
     let expected = quote! {
         impl ::enum_tree::EnumTree<RootOne> for Leaf { type P = Parent; }
         impl ::enum_tree::EnumTreeLeaf<RootOne> for Leaf {}
+
+        impl ::enum_tree::__private::ChildOf<Parent, RootOne> for Leaf {}
+        impl ::enum_tree::EnumTreeNodePath<RootOne> for Leaf {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<Parent as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH, "Leaf");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootOne> for Leaf
+        where
+            for<'__enum_tree> Parent: ::enum_tree::__private::ChildFromRoot<RootOne, Leaf> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Parent: ::enum_tree::EnumTreeNodePath<RootOne> + ::enum_tree::__private::ParentOf<Leaf>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentRef<Parent>,
+            for<'__enum_tree> Parent: ::enum_tree::__private::Ancestry<RootOne>
+        {
+            type Depth = ::enum_tree::__private::Succ << Parent as ::enum_tree::__private::Ancestry<RootOne>>::Depth>;
+            fn from_parents(root: RootOne) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootOne>> {
+                let mismatch = match <Parent as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <Parent as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootOne) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootOne>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootOne, Parent, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootOne) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootOne, Parent, Self>(root)
+            }
+            fn from_parents_at(root: RootOne) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootOne, Parent, Self>(&root)?;
+                <Parent as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootOne> for Leaf
+        where
+            for<'__enum_tree> Parent: ::enum_tree::TryFromEnumTreeRootWithContext<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentWithContext<Parent> + ::enum_tree::TryFromParentRef<Parent>
+        {
+            type Context = (<Parent as ::enum_tree::TryFromEnumTreeRootWithContext<RootOne>>::Context, <Leaf as ::enum_tree::TryFromParentWithContext<Parent>>::Context);
+            fn from_parents_with_context(root: RootOne) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootOne, Parent, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootOne> for Leaf
+        where
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromEnumTreeRoot<RootOne>
+        {
+            type Error = ::enum_tree::Mismatch<RootOne>;
+            fn try_from(root: RootOne) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootOne>::try_from_root(root)
+            }
+        }
         impl ::enum_tree::EnumTree<RootTwo> for Leaf { type P = Parent; }
         impl ::enum_tree::EnumTreeLeaf<RootTwo> for Leaf {}
-        impl From<Leaf> for Parent {
-            fn from(value: Leaf) -> Self { Parent::Leaf(value) }
+
+        impl ::enum_tree::__private::ChildOf<Parent, RootTwo> for Leaf {}
+        impl ::enum_tree::EnumTreeNodePath<RootTwo> for Leaf {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<Parent as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH, "Leaf");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootTwo> for Leaf
+        where
+            for<'__enum_tree> Parent: ::enum_tree::__private::ChildFromRoot<RootTwo, Leaf> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Parent: ::enum_tree::EnumTreeNodePath<RootTwo> + ::enum_tree::__private::ParentOf<Leaf>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentRef<Parent>,
+            for<'__enum_tree> Parent: ::enum_tree::__private::Ancestry<RootTwo>
+        {
+            type Depth = ::enum_tree::__private::Succ << Parent as ::enum_tree::__private::Ancestry<RootTwo>>::Depth>;
+            fn from_parents(root: RootTwo) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootTwo>> {
+                let mismatch = match <Parent as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <Parent as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootTwo) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootTwo>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootTwo, Parent, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootTwo) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootTwo, Parent, Self>(root)
+            }
+            fn from_parents_at(root: RootTwo) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootTwo, Parent, Self>(&root)?;
+                <Parent as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootTwo> for Leaf
+        where
+            for<'__enum_tree> Parent: ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentWithContext<Parent> + ::enum_tree::TryFromParentRef<Parent>
+        {
+            type Context = (<Parent as ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo>>::Context, <Leaf as ::enum_tree::TryFromParentWithContext<Parent>>::Context);
+            fn from_parents_with_context(root: RootTwo) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootTwo, Parent, Self>(root)
+            }
         }
-        impl TryFrom<Parent> for Leaf {
-            type Error = (); fn try_from(value: Parent) -> Result<Self, Self::Error> {
-                if let Parent::Leaf(v) = value { Ok(v) } else { Err(()) }
+
+        impl ::core::convert::TryFrom<RootTwo> for Leaf
+        where
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromEnumTreeRoot<RootTwo>
+        {
+            type Error = ::enum_tree::Mismatch<RootTwo>;
+            fn try_from(root: RootTwo) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootTwo>::try_from_root(root)
             }
         }
+        const _: () = ::enum_tree::__private::assert_parent_variant::<Parent, Leaf>(
+            "Leaf",
+            "parent `Parent` has no variant `Leaf(Leaf)` required by `#[enum_tree_leaf]`",
+        );
     };
 
     let actual = expand_enum_tree_leaf(input);
@@ -77,16 +293,385 @@ fn leaf_multiple_roots_same_parent_alias() {
     let expected = quote! {
         impl ::enum_tree::EnumTree<RootOne> for Leaf { type P = super::Parent; }
         impl ::enum_tree::EnumTreeLeaf<RootOne> for Leaf {}
+
+        impl ::enum_tree::__private::ChildOf<super::Parent, RootOne> for Leaf {}
+        impl ::enum_tree::EnumTreeNodePath<RootOne> for Leaf {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<super::Parent as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH, "Leaf");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootOne> for Leaf
+        where
+            for<'__enum_tree> super::Parent: ::enum_tree::__private::ChildFromRoot<RootOne, Leaf> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> super::Parent: ::enum_tree::EnumTreeNodePath<RootOne> + ::enum_tree::__private::ParentOf<Leaf>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentRef<super::Parent>,
+            for<'__enum_tree> super::Parent: ::enum_tree::__private::Ancestry<RootOne>
+        {
+            type Depth = ::enum_tree::__private::Succ << super::Parent as ::enum_tree::__private::Ancestry<RootOne>>::Depth>;
+            fn from_parents(root: RootOne) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootOne>> {
+                let mismatch = match <super::Parent as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <super::Parent as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootOne) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootOne>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootOne, super::Parent, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootOne) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootOne, super::Parent, Self>(root)
+            }
+            fn from_parents_at(root: RootOne) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootOne, super::Parent, Self>(&root)?;
+                <super::Parent as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootOne> for Leaf
+        where
+            for<'__enum_tree> super::Parent: ::enum_tree::TryFromEnumTreeRootWithContext<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentWithContext<super::Parent> + ::enum_tree::TryFromParentRef<super::Parent>
+        {
+            type Context = (<super::Parent as ::enum_tree::TryFromEnumTreeRootWithContext<RootOne>>::Context, <Leaf as ::enum_tree::TryFromParentWithContext<super::Parent>>::Context);
+            fn from_parents_with_context(root: RootOne) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootOne, super::Parent, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootOne> for Leaf
+        where
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromEnumTreeRoot<RootOne>
+        {
+            type Error = ::enum_tree::Mismatch<RootOne>;
+            fn try_from(root: RootOne) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootOne>::try_from_root(root)
+            }
+        }
         impl ::enum_tree::EnumTree<RootTwo> for Leaf { type P = crate::mods::Parent; }
         impl ::enum_tree::EnumTreeLeaf<RootTwo> for Leaf {}
-        impl From<Leaf> for super::Parent {
-            fn from(value: Leaf) -> Self { super::Parent::Leaf(value) }
+
+        impl ::enum_tree::__private::ChildOf<crate::mods::Parent, RootTwo> for Leaf {}
+        impl ::enum_tree::EnumTreeNodePath<RootTwo> for Leaf {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<crate::mods::Parent as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH, "Leaf");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootTwo> for Leaf
+        where
+            for<'__enum_tree> crate::mods::Parent: ::enum_tree::__private::ChildFromRoot<RootTwo, Leaf> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> crate::mods::Parent: ::enum_tree::EnumTreeNodePath<RootTwo> + ::enum_tree::__private::ParentOf<Leaf>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentRef<crate::mods::Parent>,
+            for<'__enum_tree> crate::mods::Parent: ::enum_tree::__private::Ancestry<RootTwo>
+        {
+            type Depth = ::enum_tree::__private::Succ << crate::mods::Parent as ::enum_tree::__private::Ancestry<RootTwo>>::Depth>;
+            fn from_parents(root: RootTwo) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootTwo>> {
+                let mismatch = match <crate::mods::Parent as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <crate::mods::Parent as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootTwo) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootTwo>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootTwo, crate::mods::Parent, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootTwo) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootTwo, crate::mods::Parent, Self>(root)
+            }
+            fn from_parents_at(root: RootTwo) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootTwo, crate::mods::Parent, Self>(&root)?;
+                <crate::mods::Parent as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootTwo> for Leaf
+        where
+            for<'__enum_tree> crate::mods::Parent: ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentWithContext<crate::mods::Parent> + ::enum_tree::TryFromParentRef<crate::mods::Parent>
+        {
+            type Context = (<crate::mods::Parent as ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo>>::Context, <Leaf as ::enum_tree::TryFromParentWithContext<crate::mods::Parent>>::Context);
+            fn from_parents_with_context(root: RootTwo) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootTwo, crate::mods::Parent, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootTwo> for Leaf
+        where
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromEnumTreeRoot<RootTwo>
+        {
+            type Error = ::enum_tree::Mismatch<RootTwo>;
+            fn try_from(root: RootTwo) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootTwo>::try_from_root(root)
+            }
+        }
+        const _: () = ::enum_tree::__private::assert_parent_variant::<super::Parent, Leaf>(
+            "Leaf",
+            "parent `super::Parent` has no variant `Leaf(Leaf)` required by `#[enum_tree_leaf]`",
+        );
+        const _: () = ::enum_tree::__private::assert_parent_variant::<crate::mods::Parent, Leaf>(
+            "Leaf",
+            "parent `crate::mods::Parent` has no variant `Leaf(Leaf)` required by `#[enum_tree_leaf]`",
+        );
+    };
+
+    let actual = expand_enum_tree_leaf(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn leaf_same_name_parents_in_different_modules() {
+    // Parents are distinct types that only share their last path segment; both are checked.
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(a::Menu, RootOne)]
+        #[enum_tree_leaf(b::Menu, RootTwo)]
+        pub enum Leaf { Action }
+    };
+
+    let expected = quote! {
+        impl ::enum_tree::EnumTree<RootOne> for Leaf { type P = a::Menu; }
+        impl ::enum_tree::EnumTreeLeaf<RootOne> for Leaf {}
+
+        impl ::enum_tree::__private::ChildOf<a::Menu, RootOne> for Leaf {}
+        impl ::enum_tree::EnumTreeNodePath<RootOne> for Leaf {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<a::Menu as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH, "Leaf");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootOne> for Leaf
+        where
+            for<'__enum_tree> a::Menu: ::enum_tree::__private::ChildFromRoot<RootOne, Leaf> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> a::Menu: ::enum_tree::EnumTreeNodePath<RootOne> + ::enum_tree::__private::ParentOf<Leaf>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentRef<a::Menu>,
+            for<'__enum_tree> a::Menu: ::enum_tree::__private::Ancestry<RootOne>
+        {
+            type Depth = ::enum_tree::__private::Succ << a::Menu as ::enum_tree::__private::Ancestry<RootOne>>::Depth>;
+            fn from_parents(root: RootOne) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootOne>> {
+                let mismatch = match <a::Menu as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <a::Menu as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootOne) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootOne>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootOne, a::Menu, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootOne) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootOne, a::Menu, Self>(root)
+            }
+            fn from_parents_at(root: RootOne) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootOne, a::Menu, Self>(&root)?;
+                <a::Menu as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootOne> for Leaf
+        where
+            for<'__enum_tree> a::Menu: ::enum_tree::TryFromEnumTreeRootWithContext<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentWithContext<a::Menu> + ::enum_tree::TryFromParentRef<a::Menu>
+        {
+            type Context = (<a::Menu as ::enum_tree::TryFromEnumTreeRootWithContext<RootOne>>::Context, <Leaf as ::enum_tree::TryFromParentWithContext<a::Menu>>::Context);
+            fn from_parents_with_context(root: RootOne) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootOne, a::Menu, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootOne> for Leaf
+        where
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromEnumTreeRoot<RootOne>
+        {
+            type Error = ::enum_tree::Mismatch<RootOne>;
+            fn try_from(root: RootOne) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootOne>::try_from_root(root)
+            }
+        }
+        impl ::enum_tree::EnumTree<RootTwo> for Leaf { type P = b::Menu; }
+        impl ::enum_tree::EnumTreeLeaf<RootTwo> for Leaf {}
+
+        impl ::enum_tree::__private::ChildOf<b::Menu, RootTwo> for Leaf {}
+        impl ::enum_tree::EnumTreeNodePath<RootTwo> for Leaf {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<b::Menu as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH, "Leaf");
         }
-        impl TryFrom<super::Parent> for Leaf {
-            type Error = (); fn try_from(value: super::Parent) -> Result<Self, Self::Error> {
-                if let super::Parent::Leaf(v) = value { Ok(v) } else { Err(()) }
+
+        impl ::enum_tree::__private::Ancestry<RootTwo> for Leaf
+        where
+            for<'__enum_tree> b::Menu: ::enum_tree::__private::ChildFromRoot<RootTwo, Leaf> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> b::Menu: ::enum_tree::EnumTreeNodePath<RootTwo> + ::enum_tree::__private::ParentOf<Leaf>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentRef<b::Menu>,
+            for<'__enum_tree> b::Menu: ::enum_tree::__private::Ancestry<RootTwo>
+        {
+            type Depth = ::enum_tree::__private::Succ << b::Menu as ::enum_tree::__private::Ancestry<RootTwo>>::Depth>;
+            fn from_parents(root: RootTwo) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootTwo>> {
+                let mismatch = match <b::Menu as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <b::Menu as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootTwo) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootTwo>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootTwo, b::Menu, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootTwo) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootTwo, b::Menu, Self>(root)
+            }
+            fn from_parents_at(root: RootTwo) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootTwo, b::Menu, Self>(&root)?;
+                <b::Menu as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root).ok().map(|node| (node, position))
             }
         }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootTwo> for Leaf
+        where
+            for<'__enum_tree> b::Menu: ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromParentWithContext<b::Menu> + ::enum_tree::TryFromParentRef<b::Menu>
+        {
+            type Context = (<b::Menu as ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo>>::Context, <Leaf as ::enum_tree::TryFromParentWithContext<b::Menu>>::Context);
+            fn from_parents_with_context(root: RootTwo) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootTwo, b::Menu, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootTwo> for Leaf
+        where
+            for<'__enum_tree> Leaf: ::enum_tree::TryFromEnumTreeRoot<RootTwo>
+        {
+            type Error = ::enum_tree::Mismatch<RootTwo>;
+            fn try_from(root: RootTwo) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootTwo>::try_from_root(root)
+            }
+        }
+        const _: () = ::enum_tree::__private::assert_parent_variant::<a::Menu, Leaf>(
+            "Leaf",
+            "parent `a::Menu` has no variant `Leaf(Leaf)` required by `#[enum_tree_leaf]`",
+        );
+        const _: () = ::enum_tree::__private::assert_parent_variant::<b::Menu, Leaf>(
+            "Leaf",
+            "parent `b::Menu` has no variant `Leaf(Leaf)` required by `#[enum_tree_leaf]`",
+        );
+    };
+
+    let actual = expand_enum_tree_leaf(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn leaf_several_parents_in_one_root() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(File, AppAction)]
+        #[enum_tree_leaf(Network, AppAction)]
+        pub enum Confirm { Yes, No }
+    };
+
+    let expected = quote! {
+
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for Confirm where AppAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = File; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for Confirm where AppAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<File, __EnumTreeRoot> for Confirm where AppAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<Network, __EnumTreeRoot> for Confirm where AppAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for Confirm
+        where
+            AppAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            File: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<File as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "Confirm");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for Confirm
+        where
+            AppAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> File: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Confirm> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> File: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Confirm>,
+            for<'__enum_tree> Confirm: ::enum_tree::TryFromParentRef<File>,
+            for<'__enum_tree> Network: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Confirm> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Network: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Confirm>,
+            for<'__enum_tree> Confirm: ::enum_tree::TryFromParentRef<Network>,
+            for<'__enum_tree> File: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << File as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <File as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <File as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                let (expected, found) = (mismatch.expected(), mismatch.found());
+                let mismatch = match <Network as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(mismatch.into_inner()) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <Network as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(next) => ::enum_tree::Mismatch::new(next.into_inner(), expected, found),
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, File, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                if let ::core::result::Result::Ok(node) = ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, Network, Self>(root) {
+                    return ::core::result::Result::Ok(node);
+                }
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                if ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, File, Self>(root).is_ok() {
+                    return ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, File, Self>(root);
+                }
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, Network, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                if let ::core::option::Option::Some(position) = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, File, Self>(&root) {
+                    return <File as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position));
+                }
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, Network, Self>(&root)?;
+                <Network as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for Confirm
+        where
+            AppAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> File: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Confirm: ::enum_tree::TryFromParentWithContext<File> + ::enum_tree::TryFromParentRef<File>,
+            for<'__enum_tree> Network: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot, Context = <File as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> >::Context> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Confirm: ::enum_tree::TryFromParentWithContext<Network, Context = <Confirm as ::enum_tree::TryFromParentWithContext<File> >::Context> + ::enum_tree::TryFromParentRef<Network>
+        {
+            type Context = (<File as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <Confirm as ::enum_tree::TryFromParentWithContext<File>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                if ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, File, Self>(&root).is_ok() {
+                    return ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, File, Self>(root);
+                }
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, Network, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<AppAction> for Confirm
+        where
+            for<'__enum_tree> Confirm: ::enum_tree::TryFromEnumTreeRoot<AppAction>
+        {
+            type Error = ::enum_tree::Mismatch<AppAction>;
+            fn try_from(root: AppAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<AppAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<File, Confirm>(
+            "Confirm",
+            "parent `File` has no variant `Confirm(Confirm)` required by `#[enum_tree_leaf]`",
+        );
+        const _: () = ::enum_tree::__private::assert_parent_variant::<Network, Confirm>(
+            "Confirm",
+            "parent `Network` has no variant `Confirm(Confirm)` required by `#[enum_tree_leaf]`",
+        );
     };
 
     let actual = expand_enum_tree_leaf(input);
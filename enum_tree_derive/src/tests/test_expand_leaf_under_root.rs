@@ -12,19 +12,78 @@ fn leaf_directly_under_root() {
     };
 
     let expected = quote! {
-        impl ::enum_tree::EnumTree<RootAction> for AudioActions { type P = RootAction; }
-        impl ::enum_tree::EnumTreeLeaf<RootAction> for AudioActions {}
 
-        impl From<AudioActions> for RootAction {
-            fn from(value: AudioActions) -> Self { RootAction::AudioActions(value) }
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for AudioActions where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = RootAction; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for AudioActions where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<RootAction, __EnumTreeRoot> for AudioActions where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for AudioActions
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "AudioActions");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for AudioActions
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, AudioActions> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<AudioActions>,
+            for<'__enum_tree> AudioActions: ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, RootAction, Self>(&root)?;
+                <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
         }
 
-        impl TryFrom<RootAction> for AudioActions {
-            type Error = ();
-            fn try_from(value: RootAction) -> Result<Self, Self::Error> {
-                if let RootAction::AudioActions(v) = value { Ok(v) } else { Err(()) }
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for AudioActions
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> AudioActions: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <AudioActions as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for AudioActions
+        where
+            for<'__enum_tree> AudioActions: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
             }
         }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<RootAction, AudioActions>(
+            "AudioActions",
+            "parent `RootAction` has no variant `AudioActions(AudioActions)` required by `#[enum_tree_leaf]`",
+        );
     };
 
     let actual = expand_enum_tree_leaf(input);
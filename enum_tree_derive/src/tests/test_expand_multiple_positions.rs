@@ -0,0 +1,272 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{expand_enum_tree_inner, expand_enum_tree_leaf};
+
+#[test]
+fn inner_with_child_in_several_variants() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction)]
+        pub enum Toolbar {
+            Primary(Button),
+            Secondary(Box<Button>),
+            Close,
+        }
+    };
+
+    let expected = quote! {
+
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for Toolbar where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = RootAction; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for Toolbar where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<RootAction, __EnumTreeRoot> for Toolbar where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for Toolbar
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "Toolbar");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for Toolbar
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Toolbar> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Toolbar>,
+            for<'__enum_tree> Toolbar: ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, RootAction, Self>(&root)?;
+                <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for Toolbar
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Toolbar: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <Toolbar as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for Toolbar
+        where
+            for<'__enum_tree> Toolbar: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<RootAction, Toolbar>(
+            "Toolbar",
+            "parent `RootAction` has no variant `Toolbar(Toolbar)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Button, Toolbar, RootAction>();
+
+        impl ::core::convert::TryFrom<Toolbar> for Button {
+            type Error = ::enum_tree::Mismatch<Toolbar>;
+            fn try_from(value: Toolbar) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    Toolbar::Primary(v) => ::core::result::Result::Ok(v),
+                    Toolbar::Secondary(v) => ::core::result::Result::Ok(*v),
+                    #[allow(unreachable_patterns)]
+                    _ => {
+                        let found = <Toolbar as ::enum_tree::__private::VariantName>::variant_name(&value);
+                        ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Primary", found))
+                    },
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Button> for Toolbar {
+            const VARIANTS: &'static [&'static str] = &["Primary", "Secondary"];
+            fn position(&self) -> &'static str {
+                match self {
+                    Toolbar::Primary { .. } => "Primary",
+                    Toolbar::Secondary { .. } => "Secondary",
+                    #[allow(unreachable_patterns)]
+                    _ => "Primary",
+                }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Toolbar> for Button {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Toolbar) -> ::core::option::Option<(Self, Self::Context)> {
+                match parent {
+                    Toolbar::Primary(v) => ::core::option::Option::Some((v, ())),
+                    Toolbar::Secondary(v) => ::core::option::Option::Some((*v, ())),
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Toolbar> for Button {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Toolbar) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Toolbar>> {
+                match parent {
+                    Toolbar::Primary(v) => ::core::result::Result::Ok(v),
+                    Toolbar::Secondary(v) => ::core::result::Result::Ok(&**v),
+                    #[allow(unreachable_patterns)]
+                    _ => {
+                        let found = <Toolbar as ::enum_tree::__private::VariantName>::variant_name(parent);
+                        ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Primary", found))
+                    },
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Toolbar) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Toolbar>> {
+                match parent {
+                    Toolbar::Primary(v) => ::core::result::Result::Ok(v),
+                    Toolbar::Secondary(v) => ::core::result::Result::Ok(&mut **v),
+                    #[allow(unreachable_patterns)]
+                    _ => {
+                        let found = <Toolbar as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                        ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Primary", found))
+                    },
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for Toolbar {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    Toolbar::Primary { .. } => "Primary",
+                    Toolbar::Secondary { .. } => "Secondary",
+                    Toolbar::Close { .. } => "Close",
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot, __EnumTreeChild> ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, __EnumTreeChild> for Toolbar
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            __EnumTreeChild: ::core::convert::TryFrom<Toolbar, Error = ::enum_tree::Mismatch<Toolbar> > + ::enum_tree::TryFromParentRef<Toolbar>,
+            for<'__enum_tree> Toolbar: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>
+        {
+            fn child_from_root(root: __EnumTreeRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, Toolbar, __EnumTreeChild>(root)
+            }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for Toolbar {
+            type Root = RootAction;
+        }
+    };
+
+    let actual = expand_enum_tree_inner(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn leaf_names_one_of_several_positions() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(Toolbar, RootAction, variant = Secondary)]
+        pub enum Button { Ok }
+    };
+
+    let expected = quote! {
+
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for Button where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = Toolbar; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for Button where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<Toolbar, __EnumTreeRoot> for Button where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for Button
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            Toolbar: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<Toolbar as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "Secondary");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for Button
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> Toolbar: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Button> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Toolbar: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Button>,
+            for<'__enum_tree> Button: ::enum_tree::TryFromParentRef<Toolbar>,
+            for<'__enum_tree> Toolbar: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << Toolbar as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <Toolbar as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <Toolbar as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, Toolbar, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, Toolbar, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, Toolbar, Self>(&root)?;
+                <Toolbar as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for Button
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> Toolbar: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Button: ::enum_tree::TryFromParentWithContext<Toolbar> + ::enum_tree::TryFromParentRef<Toolbar>
+        {
+            type Context = (<Toolbar as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <Button as ::enum_tree::TryFromParentWithContext<Toolbar>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, Toolbar, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for Button
+        where
+            for<'__enum_tree> Button: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<Toolbar, Button>(
+            "Secondary",
+            "parent `Toolbar` has no variant `Secondary(Button)` required by `#[enum_tree_leaf]`",
+        );
+    };
+
+    let actual = expand_enum_tree_leaf(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
@@ -14,19 +14,149 @@ fn test_inner_simple() {
     };
 
     let expected = quote! {
-        impl ::enum_tree::EnumTree<RootAction> for MenuFlow { type P = RootAction; }
-        impl ::enum_tree::EnumTreeInner<RootAction> for MenuFlow {}
 
-        impl From<MenuFlow> for RootAction {
-            fn from(value: MenuFlow) -> Self { RootAction::MenuFlow(value) }
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for MenuFlow where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = RootAction; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for MenuFlow where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<RootAction, __EnumTreeRoot> for MenuFlow where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for MenuFlow
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "MenuFlow");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for MenuFlow
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, MenuFlow> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, RootAction, Self>(&root)?;
+                <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for MenuFlow
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <MenuFlow as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for MenuFlow
+        where
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<RootAction, MenuFlow>(
+            "MenuFlow",
+            "parent `RootAction` has no variant `MenuFlow(MenuFlow)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<General, MenuFlow, RootAction>();
+
+        impl ::core::convert::From<General> for MenuFlow {
+            fn from(value: General) -> Self { Self::General(value) }
         }
 
-        impl TryFrom<RootAction> for MenuFlow {
-            type Error = ();
-            fn try_from(value: RootAction) -> Result<Self, Self::Error> {
-                if let RootAction::MenuFlow(v) = value { Ok(v) } else { Err(()) }
+        impl ::core::convert::TryFrom<MenuFlow> for General {
+            type Error = ::enum_tree::Mismatch<MenuFlow>;
+            fn try_from(value: MenuFlow) -> ::core::result::Result<Self, Self::Error> {
+                if let MenuFlow::General(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "General", found))
+                }
             }
         }
+
+        impl ::enum_tree::__private::ParentOf<General> for MenuFlow {
+            const VARIANTS: &'static [&'static str] = &["General"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<MenuFlow> for General {
+            type Context = ();
+            fn try_from_parent_with_context(parent: MenuFlow) -> ::core::option::Option<(Self, Self::Context)> {
+                if let MenuFlow::General(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<MenuFlow> for General {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree MenuFlow) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree MenuFlow>> {
+                if let MenuFlow::General(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "General", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut MenuFlow) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut MenuFlow>> {
+                if let MenuFlow::General(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "General", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for MenuFlow {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    MenuFlow::General { .. } => "General",
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot, __EnumTreeChild> ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, __EnumTreeChild> for MenuFlow
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            __EnumTreeChild: ::core::convert::TryFrom<MenuFlow, Error = ::enum_tree::Mismatch<MenuFlow> > + ::enum_tree::TryFromParentRef<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>
+        {
+            fn child_from_root(root: __EnumTreeRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, MenuFlow, __EnumTreeChild>(root)
+            }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for MenuFlow {
+            type Root = RootAction;
+        }
     };
 
     let actual = expand_enum_tree_inner(input);
@@ -45,19 +175,362 @@ fn test_inner_nested_child() {
     };
 
     let expected = quote! {
-        impl ::enum_tree::EnumTree<RootAction> for Settings { type P = MenuFlow; }
-        impl ::enum_tree::EnumTreeInner<RootAction> for Settings {}
 
-        impl From<Settings> for MenuFlow {
-            fn from(value: Settings) -> Self { MenuFlow::Settings(value) }
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for Settings where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = MenuFlow; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for Settings where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<MenuFlow, __EnumTreeRoot> for Settings where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for Settings
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            MenuFlow: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<MenuFlow as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "Settings");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for Settings
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Settings> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Settings>,
+            for<'__enum_tree> Settings: ::enum_tree::TryFromParentRef<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << MenuFlow as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <MenuFlow as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <MenuFlow as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, MenuFlow, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, MenuFlow, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, MenuFlow, Self>(&root)?;
+                <MenuFlow as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for Settings
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Settings: ::enum_tree::TryFromParentWithContext<MenuFlow> + ::enum_tree::TryFromParentRef<MenuFlow>
+        {
+            type Context = (<MenuFlow as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <Settings as ::enum_tree::TryFromParentWithContext<MenuFlow>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, MenuFlow, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for Settings
+        where
+            for<'__enum_tree> Settings: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<MenuFlow, Settings>(
+            "Settings",
+            "parent `MenuFlow` has no variant `Settings(Settings)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Audio, Settings, RootAction>();
+
+        impl ::core::convert::From<Audio> for Settings {
+            fn from(value: Audio) -> Self { Self::Audio(value) }
+        }
+
+        impl ::core::convert::TryFrom<Settings> for Audio {
+            type Error = ::enum_tree::Mismatch<Settings>;
+            fn try_from(value: Settings) -> ::core::result::Result<Self, Self::Error> {
+                if let Settings::Audio(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Settings as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Audio", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Audio> for Settings {
+            const VARIANTS: &'static [&'static str] = &["Audio"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Settings> for Audio {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Settings) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Settings::Audio(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Settings> for Audio {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Settings) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Settings>> {
+                if let Settings::Audio(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Settings as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Audio", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Settings) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Settings>> {
+                if let Settings::Audio(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Settings as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Audio", found))
+                }
+            }
+        }
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Video, Settings, RootAction>();
+
+        impl ::core::convert::From<Video> for Settings {
+            fn from(value: Video) -> Self { Self::Video(value) }
+        }
+
+        impl ::core::convert::TryFrom<Settings> for Video {
+            type Error = ::enum_tree::Mismatch<Settings>;
+            fn try_from(value: Settings) -> ::core::result::Result<Self, Self::Error> {
+                if let Settings::Video(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Settings as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Video", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Video> for Settings {
+            const VARIANTS: &'static [&'static str] = &["Video"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Settings> for Video {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Settings) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Settings::Video(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Settings> for Video {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Settings) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Settings>> {
+                if let Settings::Video(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Settings as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Video", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Settings) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Settings>> {
+                if let Settings::Video(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Settings as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Video", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for Settings {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    Settings::Audio { .. } => "Audio",
+                    Settings::Video { .. } => "Video",
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot, __EnumTreeChild> ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, __EnumTreeChild> for Settings
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            __EnumTreeChild: ::core::convert::TryFrom<Settings, Error = ::enum_tree::Mismatch<Settings> > + ::enum_tree::TryFromParentRef<Settings>,
+            for<'__enum_tree> Settings: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>
+        {
+            fn child_from_root(root: __EnumTreeRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, Settings, __EnumTreeChild>(root)
+            }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for Settings {
+            type Root = RootAction;
+        }
+    };
+
+    let actual = expand_enum_tree_inner(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_inner_with_leaf_variants() {
+    // Unit and struct variants are leaves of the inner node itself; only `Settings` is a child.
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction)]
+        pub enum Menu {
+            Settings(Settings),
+            Quit,
+            Resize { w: u32 },
+        }
+    };
+
+    let expected = quote! {
+
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for Menu where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = RootAction; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for Menu where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<RootAction, __EnumTreeRoot> for Menu where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for Menu
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "Menu");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for Menu
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Menu> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Menu>,
+            for<'__enum_tree> Menu: ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, RootAction, Self>(&root)?;
+                <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for Menu
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Menu: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <Menu as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for Menu
+        where
+            for<'__enum_tree> Menu: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<RootAction, Menu>(
+            "Menu",
+            "parent `RootAction` has no variant `Menu(Menu)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Settings, Menu, RootAction>();
+
+        impl ::core::convert::From<Settings> for Menu {
+            fn from(value: Settings) -> Self { Self::Settings(value) }
         }
 
-        impl TryFrom<MenuFlow> for Settings {
-            type Error = ();
-            fn try_from(value: MenuFlow) -> Result<Self, Self::Error> {
-                if let MenuFlow::Settings(v) = value { Ok(v) } else { Err(()) }
+        impl ::core::convert::TryFrom<Menu> for Settings {
+            type Error = ::enum_tree::Mismatch<Menu>;
+            fn try_from(value: Menu) -> ::core::result::Result<Self, Self::Error> {
+                if let Menu::Settings(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Settings", found))
+                }
             }
         }
+
+        impl ::enum_tree::__private::ParentOf<Settings> for Menu {
+            const VARIANTS: &'static [&'static str] = &["Settings"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Menu> for Settings {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Menu) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Menu::Settings(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Menu> for Settings {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Menu) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Menu>> {
+                if let Menu::Settings(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Settings", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Menu) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Menu>> {
+                if let Menu::Settings(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Settings", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for Menu {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    Menu::Settings { .. } => "Settings",
+                    Menu::Quit { .. } => "Quit",
+                    Menu::Resize { .. } => "Resize",
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot, __EnumTreeChild> ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, __EnumTreeChild> for Menu
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            __EnumTreeChild: ::core::convert::TryFrom<Menu, Error = ::enum_tree::Mismatch<Menu> > + ::enum_tree::TryFromParentRef<Menu>,
+            for<'__enum_tree> Menu: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>
+        {
+            fn child_from_root(root: __EnumTreeRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, Menu, __EnumTreeChild>(root)
+            }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for Menu {
+            type Root = RootAction;
+        }
     };
 
     let actual = expand_enum_tree_inner(input);
@@ -16,25 +16,78 @@ fn test_simple() {
     };
 
     let expected = quote! {
-        impl ::enum_tree::EnumTree<RootAction> for General { type P = MenuFlow; }
-        impl ::enum_tree::EnumTreeLeaf<RootAction> for General {}
 
-        impl From<General> for MenuFlow {
-            fn from(value: General) -> Self {
-                MenuFlow::General(value)
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for General where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = MenuFlow; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for General where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<MenuFlow, __EnumTreeRoot> for General where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for General
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            MenuFlow: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<MenuFlow as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "General");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for General
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, General> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<General>,
+            for<'__enum_tree> General: ::enum_tree::TryFromParentRef<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << MenuFlow as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <MenuFlow as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <MenuFlow as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, MenuFlow, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, MenuFlow, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, MenuFlow, Self>(&root)?;
+                <MenuFlow as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
             }
         }
 
-        impl TryFrom<MenuFlow> for General {
-            type Error = ();
-            fn try_from(value: MenuFlow) -> Result<Self, Self::Error> {
-                if let MenuFlow::General(v) = value {
-                    Ok(v)
-                } else {
-                    Err(())
-                }
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for General
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> General: ::enum_tree::TryFromParentWithContext<MenuFlow> + ::enum_tree::TryFromParentRef<MenuFlow>
+        {
+            type Context = (<MenuFlow as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <General as ::enum_tree::TryFromParentWithContext<MenuFlow>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, MenuFlow, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for General
+        where
+            for<'__enum_tree> General: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
             }
         }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<MenuFlow, General>(
+            "General",
+            "parent `MenuFlow` has no variant `General(General)` required by `#[enum_tree_leaf]`",
+        );
     };
 
     let actual = expand_enum_tree_leaf(input);
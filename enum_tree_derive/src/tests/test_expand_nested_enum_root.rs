@@ -8,7 +8,8 @@ use crate::expand_enum_tree_root;
 fn test_root_simple() {
     // Root enum with one child branch `MenuFlow` and another `AudioActions`.
     // The derive should mark it as EnumTreeRoot with Parent=(), Root=Self
-    // and implement ToEnumTreeRoot and TryFromEnumTreeRoot trivial conversions.
+    // and implement ToEnumTreeRoot and TryFromEnumTreeRoot trivial conversions, plus the
+    // conversions to and from each child it wraps.
     let input: syn::DeriveInput = parse_quote! {
         #[enum_tree_root]
         pub enum RootAction {
@@ -20,13 +21,142 @@ fn test_root_simple() {
     let expected = quote! {
         impl ::enum_tree::EnumTree<RootAction> for RootAction { type P = (); }
         impl ::enum_tree::EnumTreeRoot<RootAction> for RootAction {}
+        impl ::enum_tree::EnumTreeNodePath<RootAction> for RootAction {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::ROOT;
+        }
 
         impl ::enum_tree::ToEnumTreeRoot<RootAction> for RootAction {
             fn to_root(self) -> RootAction { self }
         }
 
-        impl ::enum_tree::TryFromEnumTreeRoot<RootAction> for RootAction {
-            fn from_root(root: RootAction) -> Option<Self> { Some(root) }
+        impl ::enum_tree::__private::Ancestry<RootAction> for RootAction {
+            type Depth = ::enum_tree::__private::Zero;
+            fn from_parents(root: RootAction) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath), ::enum_tree::Mismatch<RootAction>> {
+                ::core::result::Result::Ok((root, &::enum_tree::NodePath::ROOT))
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootAction>> {
+                ::core::result::Result::Ok(root)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootAction) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::core::option::Option::Some(root)
+            }
+            fn from_parents_at(_: RootAction) -> ::core::option::Option<(Self, &'static str)> {
+                ::core::option::Option::None
+            }
+        }
+
+        impl ::enum_tree::__private::MountedIn<RootAction> for RootAction {}
+
+        impl ::enum_tree::TryFromEnumTreeRootWithContext<RootAction> for RootAction {
+            type Context = ();
+            fn from_root_with_context(root: RootAction) -> ::core::option::Option<(Self, ())> { ::core::option::Option::Some((root, ())) }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for RootAction {
+            type Root = RootAction;
+        }
+
+        const _: () = ::enum_tree::__private::assert_child_of::<MenuFlow, RootAction, RootAction>();
+
+        impl ::core::convert::From<MenuFlow> for RootAction {
+            fn from(value: MenuFlow) -> Self { Self::MenuFlow(value) }
+        }
+
+        impl ::enum_tree::__private::ChildFromRoot<RootAction, MenuFlow> for RootAction {
+            fn child_from_root(value: RootAction) -> ::core::result::Result<MenuFlow, ::enum_tree::Mismatch<RootAction>> {
+                if let RootAction::MenuFlow(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "MenuFlow", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<MenuFlow> for RootAction {
+            const VARIANTS: &'static [&'static str] = &["MenuFlow"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<RootAction> for MenuFlow {
+            type Context = ();
+            fn try_from_parent_with_context(parent: RootAction) -> ::core::option::Option<(Self, Self::Context)> {
+                if let RootAction::MenuFlow(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<RootAction> for MenuFlow {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree RootAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootAction>> {
+                if let RootAction::MenuFlow(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "MenuFlow", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut RootAction) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut RootAction>> {
+                if let RootAction::MenuFlow(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "MenuFlow", found))
+                }
+            }
+        }
+
+        const _: () = ::enum_tree::__private::assert_child_of::<AudioActions, RootAction, RootAction>();
+
+        impl ::core::convert::From<AudioActions> for RootAction {
+            fn from(value: AudioActions) -> Self { Self::AudioActions(value) }
+        }
+
+        impl ::enum_tree::__private::ChildFromRoot<RootAction, AudioActions> for RootAction {
+            fn child_from_root(value: RootAction) -> ::core::result::Result<AudioActions, ::enum_tree::Mismatch<RootAction>> {
+                if let RootAction::AudioActions(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "AudioActions", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<AudioActions> for RootAction {
+            const VARIANTS: &'static [&'static str] = &["AudioActions"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<RootAction> for AudioActions {
+            type Context = ();
+            fn try_from_parent_with_context(parent: RootAction) -> ::core::option::Option<(Self, Self::Context)> {
+                if let RootAction::AudioActions(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<RootAction> for AudioActions {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree RootAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootAction>> {
+                if let RootAction::AudioActions(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "AudioActions", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut RootAction) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut RootAction>> {
+                if let RootAction::AudioActions(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "AudioActions", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for RootAction {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    RootAction::MenuFlow { .. } => "MenuFlow",
+                    RootAction::AudioActions { .. } => "AudioActions",
+                }
+            }
         }
     };
 
@@ -0,0 +1,218 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::expand_enum_tree_inner;
+
+#[test]
+fn inner_with_boxed_and_shared_children() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction)]
+        pub enum Menu {
+            Settings(Box<Settings>),
+            Theme(std::sync::Arc<Theme>),
+        }
+    };
+
+    let expected = quote! {
+
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for Menu where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = RootAction; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for Menu where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<RootAction, __EnumTreeRoot> for Menu where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for Menu
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "Menu");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for Menu
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Menu> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<Menu>,
+            for<'__enum_tree> Menu: ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, RootAction, Self>(&root)?;
+                <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for Menu
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Menu: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <Menu as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for Menu
+        where
+            for<'__enum_tree> Menu: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<RootAction, Menu>(
+            "Menu",
+            "parent `RootAction` has no variant `Menu(Menu)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Settings, Menu, RootAction>();
+
+        impl ::core::convert::From<Settings> for Menu {
+            fn from(value: Settings) -> Self { Self::Settings(<Box<Settings> >::new(value)) }
+        }
+
+        impl ::core::convert::TryFrom<Menu> for Settings {
+            type Error = ::enum_tree::Mismatch<Menu>;
+            fn try_from(value: Menu) -> ::core::result::Result<Self, Self::Error> {
+                if let Menu::Settings(v) = value {
+                    ::core::result::Result::Ok(*v)
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Settings", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Settings> for Menu {
+            const VARIANTS: &'static [&'static str] = &["Settings"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Menu> for Settings {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Menu) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Menu::Settings(v) = parent { ::core::option::Option::Some((*v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Menu> for Settings {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Menu) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Menu>> {
+                if let Menu::Settings(v) = parent {
+                    ::core::result::Result::Ok(&**v)
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Settings", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Menu) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Menu>> {
+                if let Menu::Settings(v) = parent {
+                    ::core::result::Result::Ok(&mut **v)
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Settings", found))
+                }
+            }
+        }
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Theme, Menu, RootAction>();
+
+        impl ::core::convert::From<Theme> for Menu {
+            fn from(value: Theme) -> Self { Self::Theme(<std::sync::Arc<Theme> >::new(value)) }
+        }
+
+        impl ::core::convert::TryFrom<Menu> for Theme {
+            type Error = ::enum_tree::Mismatch<Menu>;
+            fn try_from(value: Menu) -> ::core::result::Result<Self, Self::Error> {
+                if let Menu::Theme(v) = value {
+                    ::core::result::Result::Ok(<std::sync::Arc<Theme> >::unwrap_or_clone(v))
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Theme", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Theme> for Menu {
+            const VARIANTS: &'static [&'static str] = &["Theme"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<Menu> for Theme {
+            type Context = ();
+            fn try_from_parent_with_context(parent: Menu) -> ::core::option::Option<(Self, Self::Context)> {
+                if let Menu::Theme(v) = parent {
+                    ::core::option::Option::Some((<std::sync::Arc<Theme> >::unwrap_or_clone(v), ()))
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<Menu> for Theme {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree Menu) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree Menu>> {
+                if let Menu::Theme(v) = parent {
+                    ::core::result::Result::Ok(&**v)
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Theme", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut Menu) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut Menu>> {
+                if let Menu::Theme(v) = parent {
+                    ::core::result::Result::Ok(<std::sync::Arc<Theme> >::make_mut(v))
+                } else {
+                    let found = <Menu as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Theme", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for Menu {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    Menu::Settings { .. } => "Settings",
+                    Menu::Theme { .. } => "Theme",
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot, __EnumTreeChild> ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, __EnumTreeChild> for Menu
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            __EnumTreeChild: ::core::convert::TryFrom<Menu, Error = ::enum_tree::Mismatch<Menu> > + ::enum_tree::TryFromParentRef<Menu>,
+            for<'__enum_tree> Menu: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>
+        {
+            fn child_from_root(root: __EnumTreeRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, Menu, __EnumTreeChild>(root)
+            }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for Menu {
+            type Root = RootAction;
+        }
+    };
+
+    let actual = expand_enum_tree_inner(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
@@ -0,0 +1,207 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::expand_enum_tree;
+
+#[test]
+fn root_mounted_as_inner_node() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_root]
+        #[enum_tree_inner(AppRoot, AppRoot)]
+        pub enum PluginAction {
+            Tool(Tool),
+        }
+    };
+
+    let expected = quote! {
+        impl ::enum_tree::EnumTreePath for PluginAction
+        where
+            for<'__enum_tree> Tool: ::enum_tree::EnumTreePath
+        {
+            fn path_step(&self) -> ::core::option::Option<(&'static str, ::core::option::Option<&dyn ::enum_tree::EnumTreePath>)> {
+                match self {
+                    PluginAction::Tool(v) => ::core::option::Option::Some(("Tool", ::core::option::Option::Some(v))),
+                }
+            }
+        }
+
+        impl ::enum_tree::EnumTree<PluginAction> for PluginAction { type P = (); }
+        impl ::enum_tree::EnumTreeRoot<PluginAction> for PluginAction {}
+        impl ::enum_tree::EnumTreeNodePath<PluginAction> for PluginAction {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::ROOT;
+        }
+
+        impl ::enum_tree::ToEnumTreeRoot<PluginAction> for PluginAction {
+            fn to_root(self) -> PluginAction { self }
+        }
+
+        impl ::enum_tree::__private::Ancestry<PluginAction> for PluginAction {
+            type Depth = ::enum_tree::__private::Zero;
+            fn from_parents(root: PluginAction) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath), ::enum_tree::Mismatch<PluginAction>> {
+                ::core::result::Result::Ok((root, &::enum_tree::NodePath::ROOT))
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree PluginAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree PluginAction>> {
+                ::core::result::Result::Ok(root)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut PluginAction) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::core::option::Option::Some(root)
+            }
+            fn from_parents_at(_: PluginAction) -> ::core::option::Option<(Self, &'static str)> {
+                ::core::option::Option::None
+            }
+        }
+
+        impl ::enum_tree::__private::MountedIn<PluginAction> for PluginAction {}
+
+        impl ::enum_tree::TryFromEnumTreeRootWithContext<PluginAction> for PluginAction {
+            type Context = ();
+            fn from_root_with_context(root: PluginAction) -> ::core::option::Option<(Self, ())> { ::core::option::Option::Some((root, ())) }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for PluginAction {
+            type Root = PluginAction;
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for PluginAction where AppRoot: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = AppRoot; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for PluginAction where AppRoot: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<AppRoot, __EnumTreeRoot> for PluginAction where AppRoot: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for PluginAction
+        where
+            AppRoot: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            AppRoot: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<AppRoot as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "PluginAction");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for PluginAction
+        where
+            AppRoot: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> AppRoot: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, PluginAction> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> AppRoot: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<PluginAction>,
+            for<'__enum_tree> PluginAction: ::enum_tree::TryFromParentRef<AppRoot>,
+            for<'__enum_tree> AppRoot: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << AppRoot as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <AppRoot as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <AppRoot as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, AppRoot, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, AppRoot, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, AppRoot, Self>(&root)?;
+                <AppRoot as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for PluginAction
+        where
+            AppRoot: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> AppRoot: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> PluginAction: ::enum_tree::TryFromParentWithContext<AppRoot> + ::enum_tree::TryFromParentRef<AppRoot>
+        {
+            type Context = (<AppRoot as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <PluginAction as ::enum_tree::TryFromParentWithContext<AppRoot>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, AppRoot, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<AppRoot> for PluginAction
+        where
+            for<'__enum_tree> PluginAction: ::enum_tree::TryFromEnumTreeRoot<AppRoot>
+        {
+            type Error = ::enum_tree::Mismatch<AppRoot>;
+            fn try_from(root: AppRoot) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<AppRoot>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<AppRoot, PluginAction>(
+            "PluginAction",
+            "parent `AppRoot` has no variant `PluginAction(PluginAction)` required by `#[enum_tree_inner]`",
+        );
+
+        impl ::enum_tree::__private::MountedIn<AppRoot> for PluginAction {}
+
+        impl<__EnumTreeChild> ::enum_tree::__private::ChildFromRoot<AppRoot, __EnumTreeChild> for PluginAction
+        where
+            __EnumTreeChild: ::core::convert::TryFrom<PluginAction, Error = ::enum_tree::Mismatch<PluginAction> > + ::enum_tree::TryFromParentRef<PluginAction>,
+            for<'__enum_tree> PluginAction: ::enum_tree::TryFromEnumTreeRoot<AppRoot> + ::enum_tree::TryFromEnumTreeRootRef<AppRoot>
+        {
+            fn child_from_root(root: AppRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<AppRoot>> {
+                ::enum_tree::__private::child_from_root::<AppRoot, PluginAction, __EnumTreeChild>(root)
+            }
+        }
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Tool, PluginAction, PluginAction>();
+
+        impl ::core::convert::From<Tool> for PluginAction {
+            fn from(value: Tool) -> Self { Self::Tool(value) }
+        }
+
+        impl ::enum_tree::__private::ChildFromRoot<PluginAction, Tool> for PluginAction {
+            fn child_from_root(value: PluginAction) -> ::core::result::Result<Tool, ::enum_tree::Mismatch<PluginAction>> {
+                if let PluginAction::Tool(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <PluginAction as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Tool", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Tool> for PluginAction {
+            const VARIANTS: &'static [&'static str] = &["Tool"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<PluginAction> for Tool {
+            type Context = ();
+            fn try_from_parent_with_context(parent: PluginAction) -> ::core::option::Option<(Self, Self::Context)> {
+                if let PluginAction::Tool(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<PluginAction> for Tool {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree PluginAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree PluginAction>> {
+                if let PluginAction::Tool(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <PluginAction as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Tool", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut PluginAction) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut PluginAction>> {
+                if let PluginAction::Tool(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <PluginAction as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Tool", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for PluginAction {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    PluginAction::Tool { .. } => "Tool",
+                }
+            }
+        }
+    };
+
+    let actual = expand_enum_tree(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
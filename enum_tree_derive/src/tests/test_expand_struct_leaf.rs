@@ -0,0 +1,129 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{expand_enum_tree, expand_enum_tree_leaf, expand_enum_tree_top_down};
+
+#[test]
+fn struct_leaf() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(Audio, RootAction)]
+        pub struct SetVolume { level: u8 }
+    };
+
+    let expected = quote! {
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for SetVolume where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = Audio; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for SetVolume where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<Audio, __EnumTreeRoot> for SetVolume where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for SetVolume
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            Audio: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<Audio as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "SetVolume");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for SetVolume
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> Audio: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, SetVolume> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> Audio: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<SetVolume>,
+            for<'__enum_tree> SetVolume: ::enum_tree::TryFromParentRef<Audio>,
+            for<'__enum_tree> Audio: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << Audio as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <Audio as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <Audio as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, Audio, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, Audio, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, Audio, Self>(&root)?;
+                <Audio as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for SetVolume
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> Audio: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> SetVolume: ::enum_tree::TryFromParentWithContext<Audio> + ::enum_tree::TryFromParentRef<Audio>
+        {
+            type Context = (<Audio as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <SetVolume as ::enum_tree::TryFromParentWithContext<Audio>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, Audio, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for SetVolume
+        where
+            for<'__enum_tree> SetVolume: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<Audio, SetVolume>(
+            "SetVolume",
+            "parent `Audio` has no variant `SetVolume(SetVolume)` required by `#[enum_tree_leaf]`",
+        );
+    };
+
+    let actual = expand_enum_tree_leaf(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn top_down_struct_is_a_leaf() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub struct Mute(bool);
+    };
+
+    let expected = quote! {
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for Mute
+        where Mute: ::enum_tree::EnumTree<__EnumTreeRoot> {}
+    };
+
+    let actual = expand_enum_tree_top_down(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn struct_parents_are_rejected() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction)]
+        pub struct Audio { volume: SetVolume }
+    };
+
+    let actual = expand_enum_tree(input).to_string();
+    assert!(
+        actual.contains("EnumTree root and inner nodes must be enums; only leaves may be structs")
+    );
+}
+
+#[test]
+fn unions_are_rejected() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(Audio, RootAction)]
+        pub union Raw { a: u8 }
+    };
+
+    let actual = expand_enum_tree(input).to_string();
+    assert!(actual.contains("EnumTree cannot be derived for unions"));
+}
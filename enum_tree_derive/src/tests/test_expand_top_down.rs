@@ -0,0 +1,336 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{expand_enum_tree_root, expand_enum_tree_top_down};
+
+#[test]
+fn top_down_root_declares_children() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_root(top_down)]
+        pub enum RootAction {
+            MenuFlow(MenuFlow),
+        }
+    };
+
+    let expected = quote! {
+        impl ::enum_tree::EnumTree<RootAction> for RootAction { type P = (); }
+        impl ::enum_tree::EnumTreeRoot<RootAction> for RootAction {}
+        impl ::enum_tree::EnumTreeNodePath<RootAction> for RootAction {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::ROOT;
+        }
+
+        impl ::enum_tree::ToEnumTreeRoot<RootAction> for RootAction {
+            fn to_root(self) -> RootAction { self }
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootAction> for RootAction {
+            type Depth = ::enum_tree::__private::Zero;
+            fn from_parents(root: RootAction) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath), ::enum_tree::Mismatch<RootAction>> {
+                ::core::result::Result::Ok((root, &::enum_tree::NodePath::ROOT))
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootAction>> {
+                ::core::result::Result::Ok(root)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootAction) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::core::option::Option::Some(root)
+            }
+            fn from_parents_at(_: RootAction) -> ::core::option::Option<(Self, &'static str)> {
+                ::core::option::Option::None
+            }
+        }
+
+        impl ::enum_tree::__private::MountedIn<RootAction> for RootAction {}
+
+        impl ::enum_tree::TryFromEnumTreeRootWithContext<RootAction> for RootAction {
+            type Context = ();
+            fn from_root_with_context(root: RootAction) -> ::core::option::Option<(Self, ())> { ::core::option::Option::Some((root, ())) }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for RootAction {
+            type Root = RootAction;
+        }
+
+        impl ::enum_tree::EnumTree<RootAction> for MenuFlow { type P = RootAction; }
+        impl ::enum_tree::EnumTreeNodePath<RootAction> for MenuFlow {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<RootAction>>::PATH, "MenuFlow");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootAction> for MenuFlow
+        where
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRoot<RootAction> + ::enum_tree::TryFromEnumTreeRootRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<RootAction> + ::enum_tree::__private::ParentOf<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::core::convert::TryFrom<RootAction, Error = ::enum_tree::Mismatch<RootAction> > + ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<RootAction>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<RootAction>>::Depth>;
+            fn from_parents(root: RootAction) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootAction>> {
+                let mismatch = match ::enum_tree::__private::child_from_root::<RootAction,
+                RootAction,
+                Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<RootAction>>::PATH)),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootAction>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootAction, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootAction) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootAction, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: RootAction) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootAction, RootAction, Self>(&root)?;
+                ::enum_tree::__private::child_from_root::<RootAction, RootAction, Self>(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootAction> for MenuFlow
+        where
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<RootAction> + ::enum_tree::TryFromEnumTreeRootRef<RootAction>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<RootAction>>::Context, <MenuFlow as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: RootAction) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootAction, RootAction, Self>(root)
+            }
+        }
+
+        impl ::core::convert::From<MenuFlow> for RootAction {
+            fn from(value: MenuFlow) -> Self { Self::MenuFlow(value) }
+        }
+
+        impl ::core::convert::TryFrom<RootAction> for MenuFlow {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(value: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                if let RootAction::MenuFlow(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "MenuFlow", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<MenuFlow> for RootAction {
+            const VARIANTS: &'static [&'static str] = &["MenuFlow"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<RootAction> for MenuFlow {
+            type Context = ();
+            fn try_from_parent_with_context(parent: RootAction) -> ::core::option::Option<(Self, Self::Context)> {
+                if let RootAction::MenuFlow(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<RootAction> for MenuFlow {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree RootAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootAction>> {
+                if let RootAction::MenuFlow(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "MenuFlow", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut RootAction) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut RootAction>> {
+                if let RootAction::MenuFlow(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "MenuFlow", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for RootAction {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    RootAction::MenuFlow { .. } => "MenuFlow",
+                }
+            }
+        }
+    };
+
+    let actual = expand_enum_tree_root(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn top_down_inner_declares_children_for_any_root() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum MenuFlow {
+            General(General),
+        }
+    };
+
+    let expected = quote! {
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for MenuFlow
+        where MenuFlow: ::enum_tree::EnumTree<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for General
+        where MenuFlow: ::enum_tree::EnumTree<__EnumTreeRoot> { type P = MenuFlow; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for General
+        where
+            MenuFlow: ::enum_tree::EnumTree<__EnumTreeRoot>,
+            MenuFlow: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<MenuFlow as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "General");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for General
+        where
+            MenuFlow: ::enum_tree::EnumTree<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<General>,
+            for<'__enum_tree> General: ::core::convert::TryFrom<MenuFlow, Error = ::enum_tree::Mismatch<MenuFlow> > + ::enum_tree::TryFromParentRef<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << MenuFlow as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root::<__EnumTreeRoot,
+                MenuFlow,
+                Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <MenuFlow as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, MenuFlow, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, MenuFlow, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, MenuFlow, Self>(&root)?;
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, MenuFlow, Self>(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for General
+        where
+            MenuFlow: ::enum_tree::EnumTree<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> General: ::enum_tree::TryFromParentWithContext<MenuFlow> + ::enum_tree::TryFromParentRef<MenuFlow>
+        {
+            type Context = (<MenuFlow as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <General as ::enum_tree::TryFromParentWithContext<MenuFlow>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, MenuFlow, Self>(root)
+            }
+        }
+
+        impl ::core::convert::From<General> for MenuFlow {
+            fn from(value: General) -> Self { Self::General(value) }
+        }
+
+        impl ::core::convert::TryFrom<MenuFlow> for General {
+            type Error = ::enum_tree::Mismatch<MenuFlow>;
+            fn try_from(value: MenuFlow) -> ::core::result::Result<Self, Self::Error> {
+                if let MenuFlow::General(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "General", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<General> for MenuFlow {
+            const VARIANTS: &'static [&'static str] = &["General"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<MenuFlow> for General {
+            type Context = ();
+            fn try_from_parent_with_context(parent: MenuFlow) -> ::core::option::Option<(Self, Self::Context)> {
+                if let MenuFlow::General(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<MenuFlow> for General {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree MenuFlow) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree MenuFlow>> {
+                if let MenuFlow::General(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "General", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut MenuFlow) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut MenuFlow>> {
+                if let MenuFlow::General(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "General", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for MenuFlow {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    MenuFlow::General { .. } => "General",
+                }
+            }
+        }
+    };
+
+    let actual = expand_enum_tree_top_down(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn top_down_leaf_only_marks_itself() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum General<T> {
+            ClickBack,
+            Select { id: T },
+        }
+    };
+
+    let expected = quote! {
+        impl<T, __EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for General<T>
+        where General<T>: ::enum_tree::EnumTree<__EnumTreeRoot> {}
+    };
+
+    let actual = expand_enum_tree_top_down(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn top_down_root_rejects_multi_field_tuple_variants() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_root(top_down)]
+        pub enum RootAction {
+            Scroll(f32, f32),
+        }
+    };
+
+    let actual = expand_enum_tree_root(input).to_string();
+    assert!(actual.contains("must wrap a single child type in every tuple variant"));
+}
+
+#[test]
+fn top_down_root_keeps_leaf_variants_of_its_own() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_root(top_down)]
+        pub enum RootAction {
+            MenuFlow(MenuFlow),
+            Quit,
+            Resize { w: u32 },
+        }
+    };
+
+    let actual = expand_enum_tree_root(input).to_string();
+    assert!(!actual.contains("compile_error"), "{actual}");
+    assert!(actual.contains("impl :: enum_tree :: EnumTree < RootAction > for MenuFlow"));
+}
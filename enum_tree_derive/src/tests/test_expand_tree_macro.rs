@@ -0,0 +1,389 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::tree_macro::{TreeInput, expand_enum_tree_macro};
+
+#[test]
+fn tree_macro_declares_nested_enums() {
+    let input: TreeInput = parse_quote! {
+        #[derive(Debug)]
+        pub RootAction {
+            MenuFlow { General { ClickBack } },
+        }
+    };
+
+    let expected = quote! {
+        #[derive(Debug)]
+        pub enum RootAction { MenuFlow(MenuFlow) }
+        impl ::enum_tree::EnumTreePath for RootAction
+        where
+            for<'__enum_tree> MenuFlow: ::enum_tree::EnumTreePath
+        {
+            fn path_step(&self) -> ::core::option::Option<(&'static str, ::core::option::Option<&dyn ::enum_tree::EnumTreePath>)> {
+                match self {
+                    RootAction::MenuFlow(v) => ::core::option::Option::Some(("MenuFlow", ::core::option::Option::Some(v))),
+                }
+            }
+        }
+
+
+        impl ::enum_tree::EnumTree<RootAction> for RootAction { type P = (); }
+        impl ::enum_tree::EnumTreeRoot<RootAction> for RootAction {}
+        impl ::enum_tree::EnumTreeNodePath<RootAction> for RootAction {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::ROOT;
+        }
+
+        impl ::enum_tree::ToEnumTreeRoot<RootAction> for RootAction {
+            fn to_root(self) -> RootAction { self }
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootAction> for RootAction {
+            type Depth = ::enum_tree::__private::Zero;
+            fn from_parents(root: RootAction) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath), ::enum_tree::Mismatch<RootAction>> {
+                ::core::result::Result::Ok((root, &::enum_tree::NodePath::ROOT))
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootAction>> {
+                ::core::result::Result::Ok(root)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootAction) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::core::option::Option::Some(root)
+            }
+            fn from_parents_at(_: RootAction) -> ::core::option::Option<(Self, &'static str)> {
+                ::core::option::Option::None
+            }
+        }
+
+        impl ::enum_tree::__private::MountedIn<RootAction> for RootAction {}
+
+        impl ::enum_tree::TryFromEnumTreeRootWithContext<RootAction> for RootAction {
+            type Context = ();
+            fn from_root_with_context(root: RootAction) -> ::core::option::Option<(Self, ())> { ::core::option::Option::Some((root, ())) }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for RootAction {
+            type Root = RootAction;
+        }
+
+        const _: () = ::enum_tree::__private::assert_child_of::<MenuFlow, RootAction, RootAction>();
+
+        impl ::core::convert::From<MenuFlow> for RootAction {
+            fn from(value: MenuFlow) -> Self { Self::MenuFlow(value) }
+        }
+
+        impl ::enum_tree::__private::ChildFromRoot<RootAction, MenuFlow> for RootAction {
+            fn child_from_root(value: RootAction) -> ::core::result::Result<MenuFlow, ::enum_tree::Mismatch<RootAction>> {
+                if let RootAction::MenuFlow(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "MenuFlow", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<MenuFlow> for RootAction {
+            const VARIANTS: &'static [&'static str] = &["MenuFlow"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<RootAction> for MenuFlow {
+            type Context = ();
+            fn try_from_parent_with_context(parent: RootAction) -> ::core::option::Option<(Self, Self::Context)> {
+                if let RootAction::MenuFlow(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<RootAction> for MenuFlow {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree RootAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootAction>> {
+                if let RootAction::MenuFlow(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "MenuFlow", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut RootAction) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut RootAction>> {
+                if let RootAction::MenuFlow(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <RootAction as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "MenuFlow", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for RootAction {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    RootAction::MenuFlow { .. } => "MenuFlow",
+                }
+            }
+        }
+
+        #[derive(Debug)]
+        pub enum MenuFlow { General(General) }
+        impl ::enum_tree::EnumTreePath for MenuFlow
+        where
+            for<'__enum_tree> General: ::enum_tree::EnumTreePath
+        {
+            fn path_step(&self) -> ::core::option::Option<(&'static str, ::core::option::Option<&dyn ::enum_tree::EnumTreePath>)> {
+                match self {
+                    MenuFlow::General(v) => ::core::option::Option::Some(("General", ::core::option::Option::Some(v))),
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for MenuFlow where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = RootAction; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for MenuFlow where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<RootAction, __EnumTreeRoot> for MenuFlow where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for MenuFlow
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "MenuFlow");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for MenuFlow
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, MenuFlow> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, RootAction, Self>(&root)?;
+                <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for MenuFlow
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <MenuFlow as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for MenuFlow
+        where
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<RootAction, MenuFlow>(
+            "MenuFlow",
+            "parent `RootAction` has no variant `MenuFlow(MenuFlow)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<General, MenuFlow, RootAction>();
+
+        impl ::core::convert::From<General> for MenuFlow {
+            fn from(value: General) -> Self { Self::General(value) }
+        }
+
+        impl ::core::convert::TryFrom<MenuFlow> for General {
+            type Error = ::enum_tree::Mismatch<MenuFlow>;
+            fn try_from(value: MenuFlow) -> ::core::result::Result<Self, Self::Error> {
+                if let MenuFlow::General(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "General", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<General> for MenuFlow {
+            const VARIANTS: &'static [&'static str] = &["General"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<MenuFlow> for General {
+            type Context = ();
+            fn try_from_parent_with_context(parent: MenuFlow) -> ::core::option::Option<(Self, Self::Context)> {
+                if let MenuFlow::General(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<MenuFlow> for General {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree MenuFlow) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree MenuFlow>> {
+                if let MenuFlow::General(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "General", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut MenuFlow) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut MenuFlow>> {
+                if let MenuFlow::General(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <MenuFlow as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "General", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for MenuFlow {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    MenuFlow::General { .. } => "General",
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot, __EnumTreeChild> ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, __EnumTreeChild> for MenuFlow
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            __EnumTreeChild: ::core::convert::TryFrom<MenuFlow, Error = ::enum_tree::Mismatch<MenuFlow> > + ::enum_tree::TryFromParentRef<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>
+        {
+            fn child_from_root(root: __EnumTreeRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, MenuFlow, __EnumTreeChild>(root)
+            }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for MenuFlow {
+            type Root = RootAction;
+        }
+
+        #[derive(Debug)]
+        pub enum General { ClickBack }
+        impl ::enum_tree::EnumTreePath for General {
+            fn path_step(&self) -> ::core::option::Option<(&'static str, ::core::option::Option<&dyn ::enum_tree::EnumTreePath>)> {
+                match self {
+                    General::ClickBack { .. } => ::core::option::Option::Some(("ClickBack", ::core::option::Option::None)),
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for General where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = MenuFlow; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeLeaf<__EnumTreeRoot> for General where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<MenuFlow, __EnumTreeRoot> for General where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for General
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            MenuFlow: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<MenuFlow as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "General");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for General
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, General> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<General>,
+            for<'__enum_tree> General: ::enum_tree::TryFromParentRef<MenuFlow>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << MenuFlow as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <MenuFlow as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <MenuFlow as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, MenuFlow, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, MenuFlow, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, MenuFlow, Self>(&root)?;
+                <MenuFlow as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for General
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> MenuFlow: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> General: ::enum_tree::TryFromParentWithContext<MenuFlow> + ::enum_tree::TryFromParentRef<MenuFlow>
+        {
+            type Context = (<MenuFlow as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <General as ::enum_tree::TryFromParentWithContext<MenuFlow>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, MenuFlow, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for General
+        where
+            for<'__enum_tree> General: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<MenuFlow, General>(
+            "General",
+            "parent `MenuFlow` has no variant `General(General)` required by `#[enum_tree_leaf]`",
+        );
+
+        impl ::core::convert::From<General> for RootAction {
+            fn from(node: General) -> Self {
+                <RootAction as ::core::convert::From<MenuFlow>>::from(<MenuFlow as ::core::convert::From<General>>::from(node))
+            }
+        }
+
+        impl ::enum_tree::EnumTreeNodes for RootAction {
+            const NODES: &'static [&'static ::enum_tree::NodePath] = &[
+                <RootAction as ::enum_tree::EnumTreeNodePath<RootAction>>::PATH,
+                <MenuFlow as ::enum_tree::EnumTreeNodePath<RootAction>>::PATH,
+                <General as ::enum_tree::EnumTreeNodePath<RootAction>>::PATH
+            ];
+        }
+    };
+
+    let actual = expand_enum_tree_macro(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn tree_macro_struct_variant_is_not_a_node() {
+    let input: TreeInput = parse_quote! {
+        RootAction {
+            Audio { SetVolume { level: u8 }, Reset {} },
+        }
+    };
+
+    let actual = expand_enum_tree_macro(input).to_string();
+    let expected_leaf = "enum Audio { SetVolume { level : u8 } , Reset { } }";
+    assert!(actual.contains(expected_leaf), "{actual}");
+}
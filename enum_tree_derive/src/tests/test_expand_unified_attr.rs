@@ -0,0 +1,64 @@
+use pretty_assertions::assert_eq;
+use syn::parse_quote;
+
+use crate::expand_enum_tree;
+
+#[test]
+fn unified_attr_matches_positional_attrs() {
+    let pairs: Vec<(syn::DeriveInput, syn::DeriveInput)> = vec![
+        (
+            parse_quote! {
+                #[enum_tree(kind = root, top_down)]
+                pub enum RootAction { MenuFlow(MenuFlow) }
+            },
+            parse_quote! {
+                #[enum_tree_root(top_down)]
+                pub enum RootAction { MenuFlow(MenuFlow) }
+            },
+        ),
+        (
+            parse_quote! {
+                #[enum_tree(kind = inner, parent = RootAction, root = RootAction)]
+                pub enum MenuFlow { General(General) }
+            },
+            parse_quote! {
+                #[enum_tree_inner(RootAction, RootAction)]
+                pub enum MenuFlow { General(General) }
+            },
+        ),
+        (
+            parse_quote! {
+                #[enum_tree(parent = MenuFlow, root = RootAction, kind = leaf, variant = Main, allow_tuple)]
+                pub enum General { Scroll(f32) }
+            },
+            parse_quote! {
+                #[enum_tree_leaf(MenuFlow, RootAction, variant = Main, allow_tuple)]
+                pub enum General { Scroll(f32) }
+            },
+        ),
+    ];
+
+    for (unified, positional) in pairs {
+        assert_eq!(
+            expand_enum_tree(unified).to_string(),
+            expand_enum_tree(positional).to_string()
+        );
+    }
+}
+
+#[test]
+fn unified_attr_infers_root_from_parent() {
+    let unified: syn::DeriveInput = parse_quote! {
+        #[enum_tree(kind = leaf, parent = MenuFlow)]
+        pub enum General { ClickBack }
+    };
+    let positional: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(MenuFlow, _)]
+        pub enum General { ClickBack }
+    };
+
+    assert_eq!(
+        expand_enum_tree(unified).to_string(),
+        expand_enum_tree(positional).to_string()
+    );
+}
@@ -0,0 +1,306 @@
+use pretty_assertions::assert_eq;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{expand_enum_tree_inner, expand_enum_tree_leaf};
+
+#[test]
+fn inner_renamed_parent_variant() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_inner(RootAction, RootAction, variant = Settings)]
+        pub enum SettingsAction {
+            Audio(Audio),
+        }
+    };
+
+    let expected = quote! {
+
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTree<__EnumTreeRoot> for SettingsAction where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> { type P = RootAction; }
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeInner<__EnumTreeRoot> for SettingsAction where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+        impl<__EnumTreeRoot> ::enum_tree::__private::ChildOf<RootAction, __EnumTreeRoot> for SettingsAction where RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot> {}
+
+        impl<__EnumTreeRoot> ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> for SettingsAction
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>
+        {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH, "Settings");
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::Ancestry<__EnumTreeRoot> for SettingsAction
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, SettingsAction> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::EnumTreeNodePath<__EnumTreeRoot> + ::enum_tree::__private::ParentOf<SettingsAction>,
+            for<'__enum_tree> SettingsAction: ::enum_tree::TryFromParentRef<RootAction>,
+            for<'__enum_tree> RootAction: ::enum_tree::__private::Ancestry<__EnumTreeRoot>
+        {
+            type Depth = ::enum_tree::__private::Succ << RootAction as ::enum_tree::__private::Ancestry<__EnumTreeRoot>>::Depth>;
+            fn from_parents(root: __EnumTreeRoot) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                let mismatch = match <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <RootAction as ::enum_tree::EnumTreeNodePath<__EnumTreeRoot>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree __EnumTreeRoot) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree __EnumTreeRoot>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<__EnumTreeRoot, RootAction, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut __EnumTreeRoot) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+            fn from_parents_at(root: __EnumTreeRoot) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<__EnumTreeRoot, RootAction, Self>(&root)?;
+                <RootAction as ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl<__EnumTreeRoot> ::enum_tree::__private::FromParentsWithContext<__EnumTreeRoot> for SettingsAction
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            for<'__enum_tree> RootAction: ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>,
+            for<'__enum_tree> SettingsAction: ::enum_tree::TryFromParentWithContext<RootAction> + ::enum_tree::TryFromParentRef<RootAction>
+        {
+            type Context = (<RootAction as ::enum_tree::TryFromEnumTreeRootWithContext<__EnumTreeRoot>>::Context, <SettingsAction as ::enum_tree::TryFromParentWithContext<RootAction>>::Context);
+            fn from_parents_with_context(root: __EnumTreeRoot) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<__EnumTreeRoot, RootAction, Self>(root)
+            }
+        }
+        impl ::core::convert::TryFrom<RootAction> for SettingsAction
+        where
+            for<'__enum_tree> SettingsAction: ::enum_tree::TryFromEnumTreeRoot<RootAction>
+        {
+            type Error = ::enum_tree::Mismatch<RootAction>;
+            fn try_from(root: RootAction) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootAction>::try_from_root(root)
+            }
+        }
+
+
+        const _: () = ::enum_tree::__private::assert_parent_variant::<RootAction, SettingsAction>(
+            "Settings",
+            "parent `RootAction` has no variant `Settings(SettingsAction)` required by `#[enum_tree_inner]`",
+        );
+
+        const _: () = ::enum_tree::__private::assert_child_of::<Audio, SettingsAction, RootAction>();
+
+        impl ::core::convert::From<Audio> for SettingsAction {
+            fn from(value: Audio) -> Self { Self::Audio(value) }
+        }
+
+        impl ::core::convert::TryFrom<SettingsAction> for Audio {
+            type Error = ::enum_tree::Mismatch<SettingsAction>;
+            fn try_from(value: SettingsAction) -> ::core::result::Result<Self, Self::Error> {
+                if let SettingsAction::Audio(v) = value {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <SettingsAction as ::enum_tree::__private::VariantName>::variant_name(&value);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(value, "Audio", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::ParentOf<Audio> for SettingsAction {
+            const VARIANTS: &'static [&'static str] = &["Audio"];
+        }
+
+        impl ::enum_tree::TryFromParentWithContext<SettingsAction> for Audio {
+            type Context = ();
+            fn try_from_parent_with_context(parent: SettingsAction) -> ::core::option::Option<(Self, Self::Context)> {
+                if let SettingsAction::Audio(v) = parent { ::core::option::Option::Some((v, ())) } else { ::core::option::Option::None }
+            }
+        }
+
+        impl ::enum_tree::TryFromParentRef<SettingsAction> for Audio {
+            fn try_from_parent_ref<'__enum_tree>(parent: &'__enum_tree SettingsAction) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree SettingsAction>> {
+                if let SettingsAction::Audio(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <SettingsAction as ::enum_tree::__private::VariantName>::variant_name(parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Audio", found))
+                }
+            }
+            fn try_from_parent_mut<'__enum_tree>(parent: &'__enum_tree mut SettingsAction) -> ::core::result::Result<&'__enum_tree mut Self, ::enum_tree::Mismatch<&'__enum_tree mut SettingsAction>> {
+                if let SettingsAction::Audio(v) = parent {
+                    ::core::result::Result::Ok(v)
+                } else {
+                    let found = <SettingsAction as ::enum_tree::__private::VariantName>::variant_name(&*parent);
+                    ::core::result::Result::Err(::enum_tree::Mismatch::new(parent, "Audio", found))
+                }
+            }
+        }
+
+        impl ::enum_tree::__private::VariantName for SettingsAction {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    SettingsAction::Audio { .. } => "Audio",
+                }
+            }
+        }
+
+        impl<__EnumTreeRoot, __EnumTreeChild> ::enum_tree::__private::ChildFromRoot<__EnumTreeRoot, __EnumTreeChild> for SettingsAction
+        where
+            RootAction: ::enum_tree::__private::MountedIn<__EnumTreeRoot>,
+            __EnumTreeChild: ::core::convert::TryFrom<SettingsAction, Error = ::enum_tree::Mismatch<SettingsAction> > + ::enum_tree::TryFromParentRef<SettingsAction>,
+            for<'__enum_tree> SettingsAction: ::enum_tree::TryFromEnumTreeRoot<__EnumTreeRoot> + ::enum_tree::TryFromEnumTreeRootRef<__EnumTreeRoot>
+        {
+            fn child_from_root(root: __EnumTreeRoot) -> ::core::result::Result<__EnumTreeChild, ::enum_tree::Mismatch<__EnumTreeRoot>> {
+                ::enum_tree::__private::child_from_root::<__EnumTreeRoot, SettingsAction, __EnumTreeChild>(root)
+            }
+        }
+
+        impl ::enum_tree::__private::SingleRoot for SettingsAction {
+            type Root = RootAction;
+        }
+    };
+
+    let actual = expand_enum_tree_inner(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn leaf_renamed_parent_variant_per_root() {
+    // Each (parent, root) pair may name its own variant.
+    let input: syn::DeriveInput = parse_quote! {
+        #[enum_tree_leaf(ParentOne, RootOne, variant = Network)]
+        #[enum_tree_leaf(ParentTwo, RootTwo)]
+        pub enum NetworkAction { Connect }
+    };
+
+    let expected = quote! {
+        impl ::enum_tree::EnumTree<RootOne> for NetworkAction { type P = ParentOne; }
+        impl ::enum_tree::EnumTreeLeaf<RootOne> for NetworkAction {}
+
+        impl ::enum_tree::__private::ChildOf<ParentOne, RootOne> for NetworkAction {}
+        impl ::enum_tree::EnumTreeNodePath<RootOne> for NetworkAction {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<ParentOne as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH, "Network");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootOne> for NetworkAction
+        where
+            for<'__enum_tree> ParentOne: ::enum_tree::__private::ChildFromRoot<RootOne, NetworkAction> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> ParentOne: ::enum_tree::EnumTreeNodePath<RootOne> + ::enum_tree::__private::ParentOf<NetworkAction>,
+            for<'__enum_tree> NetworkAction: ::enum_tree::TryFromParentRef<ParentOne>,
+            for<'__enum_tree> ParentOne: ::enum_tree::__private::Ancestry<RootOne>
+        {
+            type Depth = ::enum_tree::__private::Succ << ParentOne as ::enum_tree::__private::Ancestry<RootOne>>::Depth>;
+            fn from_parents(root: RootOne) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootOne>> {
+                let mismatch = match <ParentOne as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <ParentOne as ::enum_tree::EnumTreeNodePath<RootOne>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootOne) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootOne>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootOne, ParentOne, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootOne) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootOne, ParentOne, Self>(root)
+            }
+            fn from_parents_at(root: RootOne) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootOne, ParentOne, Self>(&root)?;
+                <ParentOne as ::enum_tree::__private::ChildFromRoot<RootOne, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootOne> for NetworkAction
+        where
+            for<'__enum_tree> ParentOne: ::enum_tree::TryFromEnumTreeRootWithContext<RootOne> + ::enum_tree::TryFromEnumTreeRootRef<RootOne>,
+            for<'__enum_tree> NetworkAction: ::enum_tree::TryFromParentWithContext<ParentOne> + ::enum_tree::TryFromParentRef<ParentOne>
+        {
+            type Context = (<ParentOne as ::enum_tree::TryFromEnumTreeRootWithContext<RootOne>>::Context, <NetworkAction as ::enum_tree::TryFromParentWithContext<ParentOne>>::Context);
+            fn from_parents_with_context(root: RootOne) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootOne, ParentOne, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootOne> for NetworkAction
+        where
+            for<'__enum_tree> NetworkAction: ::enum_tree::TryFromEnumTreeRoot<RootOne>
+        {
+            type Error = ::enum_tree::Mismatch<RootOne>;
+            fn try_from(root: RootOne) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootOne>::try_from_root(root)
+            }
+        }
+        impl ::enum_tree::EnumTree<RootTwo> for NetworkAction { type P = ParentTwo; }
+        impl ::enum_tree::EnumTreeLeaf<RootTwo> for NetworkAction {}
+
+        impl ::enum_tree::__private::ChildOf<ParentTwo, RootTwo> for NetworkAction {}
+        impl ::enum_tree::EnumTreeNodePath<RootTwo> for NetworkAction {
+            const PATH: &'static ::enum_tree::NodePath = &::enum_tree::NodePath::child(<ParentTwo as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH, "NetworkAction");
+        }
+
+        impl ::enum_tree::__private::Ancestry<RootTwo> for NetworkAction
+        where
+            for<'__enum_tree> ParentTwo: ::enum_tree::__private::ChildFromRoot<RootTwo, NetworkAction> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> ParentTwo: ::enum_tree::EnumTreeNodePath<RootTwo> + ::enum_tree::__private::ParentOf<NetworkAction>,
+            for<'__enum_tree> NetworkAction: ::enum_tree::TryFromParentRef<ParentTwo>,
+            for<'__enum_tree> ParentTwo: ::enum_tree::__private::Ancestry<RootTwo>
+        {
+            type Depth = ::enum_tree::__private::Succ << ParentTwo as ::enum_tree::__private::Ancestry<RootTwo>>::Depth>;
+            fn from_parents(root: RootTwo) -> ::core::result::Result<(Self, &'static ::enum_tree::NodePath),
+            ::enum_tree::Mismatch<RootTwo>> {
+                let mismatch = match <ParentTwo as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok((node, <ParentTwo as ::enum_tree::EnumTreeNodePath<RootTwo>>::PATH)), ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_ref<'__enum_tree>(root: &'__enum_tree RootTwo) -> ::core::result::Result<&'__enum_tree Self, ::enum_tree::Mismatch<&'__enum_tree RootTwo>> {
+                let mismatch = match ::enum_tree::__private::child_from_root_ref::<RootTwo, ParentTwo, Self>(root) {
+                    ::core::result::Result::Ok(node) => return ::core::result::Result::Ok(node),
+                    ::core::result::Result::Err(mismatch) => mismatch,
+                };
+                ::core::result::Result::Err(mismatch)
+            }
+            fn from_parents_mut<'__enum_tree>(root: &'__enum_tree mut RootTwo) -> ::core::option::Option<&'__enum_tree mut Self> {
+                ::enum_tree::__private::child_from_root_mut::<RootTwo, ParentTwo, Self>(root)
+            }
+            fn from_parents_at(root: RootTwo) -> ::core::option::Option<(Self, &'static str)> {
+                let position = ::enum_tree::__private::position_in_root::<RootTwo, ParentTwo, Self>(&root)?;
+                <ParentTwo as ::enum_tree::__private::ChildFromRoot<RootTwo, Self>>::child_from_root(root).ok().map(|node| (node, position))
+            }
+        }
+
+        impl ::enum_tree::__private::FromParentsWithContext<RootTwo> for NetworkAction
+        where
+            for<'__enum_tree> ParentTwo: ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo> + ::enum_tree::TryFromEnumTreeRootRef<RootTwo>,
+            for<'__enum_tree> NetworkAction: ::enum_tree::TryFromParentWithContext<ParentTwo> + ::enum_tree::TryFromParentRef<ParentTwo>
+        {
+            type Context = (<ParentTwo as ::enum_tree::TryFromEnumTreeRootWithContext<RootTwo>>::Context, <NetworkAction as ::enum_tree::TryFromParentWithContext<ParentTwo>>::Context);
+            fn from_parents_with_context(root: RootTwo) -> ::core::option::Option<(Self, Self::Context)> {
+                ::enum_tree::__private::child_from_root_with_context::<RootTwo, ParentTwo, Self>(root)
+            }
+        }
+
+        impl ::core::convert::TryFrom<RootTwo> for NetworkAction
+        where
+            for<'__enum_tree> NetworkAction: ::enum_tree::TryFromEnumTreeRoot<RootTwo>
+        {
+            type Error = ::enum_tree::Mismatch<RootTwo>;
+            fn try_from(root: RootTwo) -> ::core::result::Result<Self, Self::Error> {
+                ::enum_tree::TryFromEnumTreeRoot::<RootTwo>::try_from_root(root)
+            }
+        }
+        const _: () = ::enum_tree::__private::assert_parent_variant::<ParentOne, NetworkAction>(
+            "Network",
+            "parent `ParentOne` has no variant `Network(NetworkAction)` required by `#[enum_tree_leaf]`",
+        );
+        const _: () = ::enum_tree::__private::assert_parent_variant::<ParentTwo, NetworkAction>(
+            "NetworkAction",
+            "parent `ParentTwo` has no variant `NetworkAction(NetworkAction)` required by `#[enum_tree_leaf]`",
+        );
+    };
+
+    let actual = expand_enum_tree_leaf(input);
+    assert_eq!(actual.to_string(), expected.to_string());
+}
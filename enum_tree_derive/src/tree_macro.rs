@@ -0,0 +1,324 @@
+//! The function-like `enum_tree!` macro, which declares a whole tree in one block.
+//!
+//! Every node is turned into an enum carrying the same tree attributes a hand-written tree
+//! would use, and its impls come from the regular derive expansion.
+
+use std::collections::HashMap;
+
+use quote::quote;
+use syn::{
+    Attribute, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Ident, ItemEnum, Token, Variant,
+    Visibility, braced,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token,
+};
+
+use crate::{Errors, attrs, child_slot, expand_enum_tree};
+
+/// Parsed `enum_tree! { #[attrs] vis Root { ... } }` input.
+pub(crate) struct TreeInput {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    root: Node,
+}
+
+/// A node of the tree: becomes an enum named `ident`.
+struct Node {
+    attrs: Vec<Attribute>,
+    ident: Ident,
+    items: Punctuated<Item, Token![,]>,
+}
+
+/// Either a child node, wrapped by a variant of the same name, or a plain variant of the
+/// enclosing node.
+enum Item {
+    Node(Node),
+    Variant(Box<Variant>),
+}
+
+impl Parse for TreeInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let root = Node::parse_body(Vec::new(), input.parse()?, input)?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(TreeInput { attrs, vis, root })
+    }
+}
+
+impl Node {
+    fn parse_body(attrs: Vec<Attribute>, ident: Ident, input: ParseStream) -> syn::Result<Self> {
+        let content;
+        braced!(content in input);
+        let items = content.parse_terminated(Item::parse, Token![,])?;
+        Ok(Node {
+            attrs,
+            ident,
+            items,
+        })
+    }
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let ident: Ident = input.parse()?;
+
+        // `Name { field: Ty, .. }` and `Name {}` are struct variants; any other braced body is a
+        // child node.
+        if input.peek(token::Brace) && !is_struct_variant_body(input) {
+            return Node::parse_body(attrs, ident, input).map(Item::Node);
+        }
+
+        let fields = if input.peek(token::Brace) {
+            Fields::Named(input.parse::<FieldsNamed>()?)
+        } else if input.peek(token::Paren) {
+            Fields::Unnamed(input.parse::<FieldsUnnamed>()?)
+        } else {
+            Fields::Unit
+        };
+        let discriminant = match input.parse::<Option<Token![=]>>()? {
+            Some(eq) => Some((eq, input.parse()?)),
+            None => None,
+        };
+        Ok(Item::Variant(Box::new(Variant {
+            attrs,
+            ident,
+            fields,
+            discriminant,
+        })))
+    }
+}
+
+fn is_struct_variant_body(input: ParseStream) -> bool {
+    let Ok(group) = input.fork().parse::<proc_macro2::Group>() else {
+        return false;
+    };
+    let looks_like_fields = |content: ParseStream| -> syn::Result<bool> {
+        if content.is_empty() {
+            return Ok(true);
+        }
+        content.call(Attribute::parse_outer)?;
+        content.parse::<Visibility>()?;
+        let is_field =
+            content.peek(Ident) && content.peek2(Token![:]) && !content.peek2(Token![::]);
+        content.parse::<proc_macro2::TokenStream>()?;
+        Ok(is_field)
+    };
+    syn::parse::Parser::parse2(looks_like_fields, group.stream()).unwrap_or(false)
+}
+
+/// Expands `enum_tree! { .. }` into one enum per node plus all of its tree impls.
+pub(crate) fn expand_enum_tree_macro(input: TreeInput) -> proc_macro2::TokenStream {
+    let mut errors = Errors::default();
+
+    // Node names double as variant names and enum names, so they must be unique tree-wide.
+    let mut seen: HashMap<String, &Node> = HashMap::new();
+    check_unique_names(&input.root, &mut seen, &mut errors);
+    // Only the first declaration of a duplicated name is generated, and only it is wrapped by its
+    // parent, so the error above is not buried under conflicting-impl errors.
+    let is_first = |node: &Node| std::ptr::eq(seen[&node.ident.to_string()], node);
+    let mut parents: HashMap<String, &Ident> = HashMap::new();
+    collect_parents(&input.root, &mut parents);
+
+    // Attributes in front of the root apply to every generated enum, except its docs.
+    let shared_attrs: Vec<&Attribute> = input
+        .attrs
+        .iter()
+        .filter(|attr| !attr.path().is_ident("doc"))
+        .collect();
+    let root_docs: Vec<&Attribute> = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .collect();
+
+    let mut tokens = proc_macro2::TokenStream::new();
+    let root = &input.root.ident;
+    let mut krate = quote!(::enum_tree);
+    let mut nodes = Vec::new();
+    // Each node with its ancestors, the root first.
+    let mut stack: Vec<(&Node, Vec<&Ident>)> = vec![(&input.root, Vec::new())];
+    while let Some((node, ancestors)) = stack.pop() {
+        if !is_first(node) {
+            continue;
+        }
+        let parent = ancestors.last().copied();
+
+        let has_nodes = node.items.iter().any(|item| matches!(item, Item::Node(_)));
+        let is_leaf = parent.is_some() && !has_nodes;
+        let tree_attr: Attribute = match parent {
+            None => syn::parse_quote!(#[enum_tree_root]),
+            Some(parent) if has_nodes => syn::parse_quote!(#[enum_tree_inner(#parent, #root)]),
+            Some(parent) => syn::parse_quote!(#[enum_tree_leaf(#parent, #root)]),
+        };
+        nodes.push(&node.ident);
+
+        // The root and inner nodes wrap the nodes declared in them; a tuple variant would wrap a
+        // child the macro doesn't know, so it is reported and left out.
+        let variants = node.items.iter().filter_map(|item| match item {
+            Item::Node(child) if !is_first(child) => None,
+            Item::Node(child) => {
+                let child_ident = &child.ident;
+                Some(syn::parse_quote!(#child_ident(#child_ident)))
+            }
+            Item::Variant(variant) if !is_leaf && matches!(variant.fields, Fields::Unnamed(_)) => {
+                errors.push(child_ref_error(node, variant, &parents));
+                None
+            }
+            Item::Variant(variant) => Some(Variant::clone(variant)),
+        });
+
+        let mut attrs: Vec<Attribute> = shared_attrs.iter().copied().cloned().collect();
+        if parent.is_none() {
+            attrs.extend(root_docs.iter().copied().cloned());
+        }
+        attrs.extend(node.attrs.iter().cloned());
+
+        let mut item = ItemEnum {
+            attrs,
+            vis: input.vis.clone(),
+            enum_token: Default::default(),
+            ident: node.ident.clone(),
+            generics: Default::default(),
+            brace_token: Default::default(),
+            variants: variants.collect(),
+        };
+
+        let mut derive_input = DeriveInput::from(item.clone());
+        derive_input.attrs.push(tree_attr);
+        if parent.is_none() {
+            krate = attrs::crate_path(&derive_input);
+        }
+        let impls = expand_enum_tree(derive_input);
+
+        // No derive is attached to the generated enum, so its `#[enum_tree(..)]` options and
+        // field markers must not be emitted.
+        item.attrs.retain(|attr| !attr.path().is_ident("enum_tree"));
+        for field in item.variants.iter_mut().flat_map(|v| v.fields.iter_mut()) {
+            field
+                .attrs
+                .retain(|attr| !attr.path().is_ident("enum_tree"));
+        }
+
+        tokens.extend(quote! {
+            #item
+            #impls
+        });
+
+        // The whole tree is known here, so every ancestor above the parent gets a concrete
+        // `From` impl, each going through the one below it, and the ancestors between the root
+        // and the parent a matching `TryFrom` impl.
+        let ident = &node.ident;
+        for (i, pair) in ancestors.windows(2).enumerate() {
+            let (ancestor, below) = (pair[0], pair[1]);
+            tokens.extend(quote! {
+                impl ::core::convert::From<#ident> for #ancestor {
+                    fn from(node: #ident) -> Self {
+                        <#ancestor as ::core::convert::From<#below>>::from(<#below as ::core::convert::From<#ident>>::from(node))
+                    }
+                }
+            });
+            if i > 0 {
+                tokens.extend(quote! {
+                    impl ::core::convert::TryFrom<#ancestor> for #ident {
+                        type Error = #krate::Mismatch<#ancestor>;
+                        fn try_from(ancestor: #ancestor) -> ::core::result::Result<Self, Self::Error> {
+                            <#ident as #krate::TryFromEnumTreeAncestor<#root>>::try_from_ancestor(ancestor)
+                        }
+                    }
+                });
+            }
+        }
+
+        for child in node.items.iter().rev() {
+            if let Item::Node(child) = child {
+                let mut child_ancestors = ancestors.clone();
+                child_ancestors.push(&node.ident);
+                stack.push((child, child_ancestors));
+            }
+        }
+    }
+
+    tokens.extend(quote! {
+        impl #krate::EnumTreeNodes for #root {
+            const NODES: &'static [&'static #krate::NodePath] = &[
+                #(<#nodes as #krate::EnumTreeNodePath<#root>>::PATH),*
+            ];
+        }
+    });
+    tokens.extend(errors.into_compile_error());
+    tokens
+}
+
+fn collect_parents<'a>(node: &'a Node, parents: &mut HashMap<String, &'a Ident>) {
+    for item in node.items.iter() {
+        if let Item::Node(child) = item {
+            parents
+                .entry(child.ident.to_string())
+                .or_insert(&node.ident);
+            collect_parents(child, parents);
+        }
+    }
+}
+
+/// The error for a tuple variant of the root or an inner node, naming where the wrapped type is
+/// declared if it is a node of the tree.
+fn child_ref_error(
+    node: &Node,
+    variant: &Variant,
+    parents: &HashMap<String, &Ident>,
+) -> syn::Error {
+    let wrapped = match &variant.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            match child_slot(&fields.unnamed[0].ty).0 {
+                syn::Type::Path(type_path) => type_path
+                    .path
+                    .segments
+                    .last()
+                    .map(|seg| seg.ident.to_string()),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    let message = match wrapped
+        .as_ref()
+        .and_then(|name| Some((name, parents.get(name)?)))
+    {
+        Some((name, parent)) => format!(
+            "enum_tree! node `{name}` is already wrapped by `{parent}`, where it is declared; a node has a single place in the tree"
+        ),
+        None => format!(
+            "enum_tree! node `{}` cannot have tuple variant `{}`: declare its children as nodes `Name {{ .. }}`, and tuple payloads in a leaf marked `#[enum_tree(allow_tuple)]`",
+            node.ident, variant.ident
+        ),
+    };
+    syn::Error::new_spanned(variant, message)
+}
+
+fn check_unique_names<'a>(
+    node: &'a Node,
+    seen: &mut HashMap<String, &'a Node>,
+    errors: &mut Errors,
+) {
+    if let Some(first) = seen.get(&node.ident.to_string()) {
+        let mut error = syn::Error::new(
+            node.ident.span(),
+            format!(
+                "enum_tree! node `{}` is declared more than once",
+                node.ident
+            ),
+        );
+        error.combine(syn::Error::new(first.ident.span(), "first declared here"));
+        errors.push(error);
+    } else {
+        seen.insert(node.ident.to_string(), node);
+    }
+    for item in node.items.iter() {
+        if let Item::Node(child) = item {
+            check_unique_names(child, seen, errors);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use enum_tree_derive::EnumTree;
+
+#[derive(EnumTree)]
+#[enum_tree_root]
+pub enum App {
+    Menu(Menu),
+}
+
+// Tuple variants of inner nodes wrap children; `allow_tuple` is for leaves.
+#[derive(EnumTree)]
+#[enum_tree_inner(App, App)]
+#[enum_tree(allow_tuple)]
+pub enum Menu {
+    Quit,
+}
+
+fn main() {}
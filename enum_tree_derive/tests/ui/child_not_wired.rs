@@ -0,0 +1,28 @@
+use enum_tree_derive::EnumTree as DeriveEnumTree;
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum RootAction {
+    MenuFlow(MenuFlow),
+    Audio(Audio),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum MenuFlow {
+    General(General),
+}
+
+// Names the root as its parent, but is wrapped by `MenuFlow`.
+#[derive(DeriveEnumTree)]
+#[enum_tree_leaf(RootAction, RootAction, variant = Audio)]
+pub enum General {
+    ClickBack,
+}
+
+// Forgot its attribute entirely.
+pub enum Audio {
+    Mute,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use enum_tree::{EnumTreeNodePath, NodePath};
+use enum_tree_derive::EnumTree as DeriveEnumTree;
+
+// A generic leaf can't check its variant name in a constant of its own; its path and its
+// conversions check it once they are used with concrete types.
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum App<T> { Menu(Menu<T>) }
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(App<T>, App<T>)]
+pub enum Menu<T> { Prefs(Settings<T>) }
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_leaf(Menu<T>, App<T>)]
+pub enum Settings<T> { Set { value: T } }
+
+const PATH: &NodePath = <Settings<u8> as EnumTreeNodePath<App<u8>>>::PATH;
+
+fn main() {
+    let _ = PATH;
+}
@@ -0,0 +1,29 @@
+use enum_tree_derive::EnumTree as DeriveEnumTree;
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum RootOne {
+    Shared(Shared),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum RootTwo {
+    Shared(Shared),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(RootOne, RootOne)]
+#[enum_tree_inner(RootTwo, RootTwo)]
+pub enum Shared {
+    Leaf(Leaf),
+}
+
+// `Shared` belongs to two trees, so the root must be named.
+#[derive(DeriveEnumTree)]
+#[enum_tree(kind = leaf, parent = Shared)]
+pub enum Leaf {
+    Action,
+}
+
+fn main() {}
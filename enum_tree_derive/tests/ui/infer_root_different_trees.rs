@@ -0,0 +1,35 @@
+use enum_tree_derive::EnumTree as DeriveEnumTree;
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum RootOne {
+    File(File),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum RootTwo {
+    Network(Network),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree(kind = inner, parent = RootOne)]
+pub enum File {
+    Confirm(Confirm),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree(kind = inner, parent = RootTwo)]
+pub enum Network {
+    Confirm(Confirm),
+}
+
+// The root is inferred from `File`; `Network` is in another tree.
+#[derive(DeriveEnumTree)]
+#[enum_tree(kind = leaf, parent = File)]
+#[enum_tree(kind = leaf, parent = Network)]
+pub enum Confirm {
+    Yes,
+}
+
+fn main() {}
@@ -1,9 +1,13 @@
 use enum_tree_derive::EnumTree as DeriveEnumTree;
 
 // Root and Parent enums
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
 pub enum RootAction {
     MenuFlow(MenuFlow),
 }
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(RootAction, RootAction)]
 pub enum MenuFlow {
     NotSettings(Settings),
 }
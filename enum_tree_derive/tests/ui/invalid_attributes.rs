@@ -0,0 +1,37 @@
+use enum_tree_derive::EnumTree as DeriveEnumTree;
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum RootAction {
+    MenuFlow(MenuFlow),
+    IpSetup(IpSetup),
+}
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum MenuFlow {
+    General(General),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner()]
+#[enum_tree_leaf(RootAction, RootAction)]
+pub enum MenuFlowInvalid {
+    General(General),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_leaf(MenuFlow)]
+#[enum_tree_leaf(MenuFlow, RootAction, Extra)]
+pub enum General {
+    ClickBack,
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_leaf(RootAction, RootAction)]
+pub enum IpSetup {
+    UpdatePortText(String),
+    ClickStartIp,
+    Scroll(f32, f32),
+}
+
+fn main() {}
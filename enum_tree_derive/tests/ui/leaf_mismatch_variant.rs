@@ -1,7 +1,11 @@
 use enum_tree_derive::EnumTree as DeriveEnumTree;
 
 // Parent and Root enums
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(RootAction, RootAction)]
 pub enum MenuFlow { NotGeneral(General) }
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
 pub enum RootAction { MenuFlow(MenuFlow) }
 
 #[derive(DeriveEnumTree)]
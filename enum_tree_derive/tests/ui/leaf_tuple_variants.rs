@@ -1,9 +1,13 @@
 use enum_tree_derive::EnumTree as DeriveEnumTree;
 
 // Parent and root enums to satisfy paths in attributes
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(RootAction, RootAction)]
 pub enum MenuFlow {
     IpSetup(IpSetup),
 }
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
 pub enum RootAction {
     MenuFlow(MenuFlow),
 }
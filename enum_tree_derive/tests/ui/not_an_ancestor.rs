@@ -0,0 +1,42 @@
+use enum_tree::{AncestorOf, DescendantOf, ToEnumTreeAncestor, TryFromEnumTreeAncestor};
+use enum_tree_derive::EnumTree as DeriveEnumTree;
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum RootAction {
+    MenuFlow(MenuFlow),
+    Audio(Audio),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum MenuFlow {
+    General(General),
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_leaf(MenuFlow, RootAction)]
+pub enum General {
+    ClickBack,
+}
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_leaf(RootAction, RootAction)]
+pub enum Audio {
+    Mute,
+}
+
+fn assert_descendant<T: DescendantOf<A, RootAction>, A>() {}
+fn assert_ancestor<T: AncestorOf<D, RootAction>, D>() {}
+
+fn main() {
+    // `Audio` is a sibling of `MenuFlow`, not one of its ancestors.
+    let _ = Audio::Mute.to_ancestor::<MenuFlow>();
+    let _ = General::try_from_ancestor(Audio::Mute);
+    assert_descendant::<Audio, MenuFlow>();
+    // A node is not its own descendant.
+    assert_descendant::<MenuFlow, MenuFlow>();
+    // The root's parent `()` is not a node.
+    assert_descendant::<RootAction, ()>();
+    assert_ancestor::<Audio, General>();
+}
@@ -0,0 +1,15 @@
+use enum_tree_derive::EnumTree as DeriveEnumTree;
+
+// The parent doesn't wrap `General` in any variant.
+#[derive(DeriveEnumTree)]
+#[enum_tree_inner(RootAction, RootAction)]
+pub enum MenuFlow { Back }
+#[derive(DeriveEnumTree)]
+#[enum_tree_root]
+pub enum RootAction { MenuFlow(MenuFlow) }
+
+#[derive(DeriveEnumTree)]
+#[enum_tree_leaf(MenuFlow, RootAction)]
+pub enum General { ClickBack }
+
+fn main() {}
@@ -0,0 +1,16 @@
+use enum_tree_derive::enum_tree;
+
+enum_tree! {
+    RootAction {
+        Menu {
+            Settings { ToggleSound },
+            Quit,
+            Shared(Settings),
+            Volume(u8),
+        },
+        Settings { Reset },
+        Network { Ping(u32) },
+    }
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+//! Stands in for an internal crate re-exporting `enum_tree` under its own path. Its tests derive
+//! trees with `#[enum_tree(crate = ..)]`, where generated code naming `::enum_tree` fails to
+//! resolve.
+#![no_std]
+
+pub use runtime as enum_tree;
@@ -0,0 +1,67 @@
+//! `::enum_tree` doesn't resolve here: the runtime is only reachable through the facade.
+
+mod app {
+    use enum_tree_facade::enum_tree::EnumTree;
+
+    // Shadow the prelude names generated code must not rely on.
+    #[allow(dead_code)]
+    type Result = ();
+    #[allow(dead_code)]
+    struct Option;
+    #[allow(dead_code)]
+    trait From {}
+    #[allow(dead_code)]
+    trait TryFrom {}
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree(kind = root, crate = enum_tree_facade::enum_tree)]
+    pub enum AppAction {
+        Menu(Menu),
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree(crate = enum_tree_facade::enum_tree)]
+    #[enum_tree_inner(AppAction, AppAction)]
+    pub enum Menu {
+        Settings(Box<Settings>),
+        Buffer {
+            id: u32,
+            #[enum_tree(child)]
+            action: Buffer,
+        },
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree(kind = leaf, parent = Menu, crate = enum_tree_facade::enum_tree)]
+    pub enum Settings {
+        ToggleSound,
+    }
+
+    #[derive(EnumTree, Clone, Debug, PartialEq)]
+    #[enum_tree(kind = leaf, parent = Menu, variant = Buffer, crate = enum_tree_facade::enum_tree)]
+    pub enum Buffer {
+        Save,
+    }
+}
+
+use app::{AppAction, Buffer, Menu, Settings};
+use enum_tree_facade::enum_tree::{ToEnumTreeRoot, TryFromEnumTreeRoot, TryFromEnumTreeRootWithContext};
+
+#[test]
+fn test_round_trip_through_facade() {
+    let root = Settings::ToggleSound.to_root();
+    assert_eq!(
+        root,
+        AppAction::Menu(Menu::Settings(Box::new(Settings::ToggleSound)))
+    );
+    assert_eq!(Settings::from_root(root), Some(Settings::ToggleSound));
+
+    let root = AppAction::Menu(Menu::Buffer {
+        id: 3,
+        action: Buffer::Save,
+    });
+    assert_eq!(
+        Buffer::from_root_with_context(root),
+        Some((Buffer::Save, (((), ()), (3,))))
+    );
+}